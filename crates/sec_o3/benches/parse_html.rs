@@ -0,0 +1,33 @@
+//! Full versus prefix-only HTML parsing on a 10-K sized document.
+//!
+//! Run with `cargo bench --bench parse_html`.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sec_o3::parse::html::{parse_html, parse_html_fast};
+
+/// About 5 MB, the size of a typical 10-K primary document.
+fn large_document() -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head><title>10-K Filing</title></head>\n<body>\n<div>FORM 10-K</div>\n{}\n</body>\n</html>\n",
+        "<p>Lorem ipsum dolor sit amet</p>".repeat(150_000)
+    )
+}
+
+fn bench_parse_html(c: &mut Criterion) {
+    let html = large_document();
+    let mut group = c.benchmark_group("parse_html_5mb");
+    group.sample_size(10);
+
+    group.bench_function("parse_html", |b| {
+        b.iter(|| parse_html(black_box(&html)).unwrap());
+    });
+    group.bench_function("parse_html_fast", |b| {
+        b.iter(|| parse_html_fast(black_box(&html)).unwrap());
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_html);
+criterion_main!(benches);
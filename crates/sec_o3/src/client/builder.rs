@@ -0,0 +1,503 @@
+//! Typed builder for [`Client`].
+//!
+//! Collects all client settings up front and validates them together in
+//! [`ClientBuilder::build`], so an invalid combination is reported before
+//! any request is made.
+use hyper::client::connect::Connect;
+use hyper::Uri;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use super::rate_limit::RateLimiter;
+use super::retry::RetryPolicy;
+use super::validation::validate_user_agent;
+use super::{transport, Client, ClientInner, Transport, DEFAULT_MAX_CONCURRENT};
+use crate::errors::{Error, Result};
+
+/// Builder for a configured [`Client`].
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::Client;
+/// use sec_o3::client::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .user_agent("MyApp contact@example.com")
+///     .timeout(Duration::from_secs(30))
+///     .rate(5)
+///     .retry(RetryPolicy::new(5))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ClientBuilder {
+    user_agents: Vec<String>,
+    timeout: Option<Duration>,
+    requests_per_second: u32,
+    retry_policy: RetryPolicy,
+    base_url: Option<String>,
+    proactive_throttle: bool,
+    max_concurrent: usize,
+    #[cfg(feature = "dangerous-tls")]
+    accept_invalid_certs: bool,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ClientBuilder");
+        debug
+            .field("user_agents", &self.user_agents)
+            .field("timeout", &self.timeout)
+            .field("requests_per_second", &self.requests_per_second)
+            .field("retry_policy", &self.retry_policy)
+            .field("base_url", &self.base_url)
+            .field("proactive_throttle", &self.proactive_throttle)
+            .field("max_concurrent", &self.max_concurrent);
+        #[cfg(feature = "dangerous-tls")]
+        debug.field("accept_invalid_certs", &self.accept_invalid_certs);
+        debug.field("custom_connector", &self.transport.is_some()).finish()
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            user_agents: Vec::new(),
+            timeout: None,
+            requests_per_second: 10,
+            retry_policy: RetryPolicy::default(),
+            base_url: None,
+            proactive_throttle: false,
+            max_concurrent: DEFAULT_MAX_CONCURRENT,
+            #[cfg(feature = "dangerous-tls")]
+            accept_invalid_certs: false,
+            transport: None,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create a builder with the SEC defaults (10 req/s, 20 requests in flight, default retry policy, no timeout).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the User-Agent sent with every request.
+    ///
+    /// Must contain an application name and a contact email per SEC requirements.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agents = vec![user_agent.into()];
+        self
+    }
+
+    /// Rotate through several User-Agents, one per request, in order.
+    ///
+    /// For long-running crawls by operators with more than one approved
+    /// contact. Each must be a valid SEC User-Agent; replaces any value set
+    /// with [`ClientBuilder::user_agent`].
+    pub fn user_agents<I>(mut self, user_agents: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.user_agents = user_agents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set a per-attempt timeout for requests.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of requests per second.
+    pub fn rate(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = requests_per_second;
+        self
+    }
+
+    /// Set the retry policy applied to every request.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Route all requests to an EDGAR mirror instead of the SEC hosts.
+    ///
+    /// The scheme and host of every request URL are replaced with those of
+    /// `base_url`, while the path and query are kept. Any path on `base_url`
+    /// is used as a prefix (e.g., `https://mirror.internal/edgar`).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Pause briefly when the SEC reports that few requests remain.
+    ///
+    /// When enabled, a response whose `x-ratelimit-remaining` header is
+    /// nearly exhausted delays the next request by one second, on top of
+    /// the client's own rate limit. Disabled by default.
+    pub fn proactive_throttle(mut self, enabled: bool) -> Self {
+        self.proactive_throttle = enabled;
+        self
+    }
+
+    /// Preset for long-running or shared jobs that should stay well clear of SEC limits.
+    ///
+    /// Half the SEC's 10 requests per second, 4 requests in flight, a
+    /// 30-second timeout, 5 attempts starting at a 500 ms backoff, and
+    /// [proactive throttling](ClientBuilder::proactive_throttle). Downloads
+    /// take about twice as long as at full rate, but the client leaves
+    /// headroom for other tools sharing the same IP address and rides out
+    /// EDGAR slowdowns instead of failing. Settings called afterwards
+    /// override the preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec_o3::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .user_agent("MyApp contact@example.com")
+    ///     .polite()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn polite(self) -> Self {
+        self.rate(5)
+            .max_concurrent(4)
+            .timeout(Duration::from_secs(30))
+            .retry(RetryPolicy {
+                max_attempts: 5,
+                initial_delay: Duration::from_millis(500),
+                ..RetryPolicy::default()
+            })
+            .proactive_throttle(true)
+    }
+
+    /// Preset for short bulk jobs that use the SEC's full request budget.
+    ///
+    /// The SEC's maximum of 10 requests per second, 50 requests in flight,
+    /// a 15-second timeout, and 2 attempts. Throughput is as high as the
+    /// SEC allows, but a burst of slow responses fails fast rather than
+    /// being retried at length, and nothing is left for other clients on
+    /// the same IP address; exceeding the limit gets the address blocked
+    /// for a while. Settings called afterwards override the preset.
+    pub fn aggressive(self) -> Self {
+        self.rate(10)
+            .max_concurrent(50)
+            .timeout(Duration::from_secs(15))
+            .retry(RetryPolicy::new(2))
+            .proactive_throttle(false)
+    }
+
+    /// Set the maximum number of requests in flight at once.
+    ///
+    /// Further requests wait for one to finish, so spawning thousands of
+    /// tasks does not open thousands of connections. A request counts,
+    /// across any retries, until its response body has been read to the end
+    /// or dropped, so streamed downloads hold their slot for their whole
+    /// length. Defaults to 20.
+    pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+        self.max_concurrent = max_concurrent;
+        self
+    }
+
+    /// **Test only.** Accept any TLS certificate and host name.
+    ///
+    /// Disables certificate verification entirely, so the client can reach
+    /// a local mock or mirror with a self-signed certificate. Anyone on the
+    /// network path can then impersonate the server: never enable this
+    /// against sec.gov or outside tests. Only available with the
+    /// `dangerous-tls` feature, and ignored when a custom
+    /// [`connector`](ClientBuilder::connector) is set.
+    #[cfg(feature = "dangerous-tls")]
+    pub fn dangerous_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Use a custom connector instead of the default HTTPS connector.
+    ///
+    /// Allows custom TLS roots, DNS resolution, proxies, or Unix sockets for
+    /// internal EDGAR mirrors. Any type implementing hyper's `Connect`
+    /// works, including a `tower::Service<Uri>` returning a connection.
+    /// Connection pooling and HTTP/2 settings still apply.
+    pub fn connector<C>(mut self, connector: C) -> Self
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        self.transport = Some(transport(connector));
+        self
+    }
+
+    /// Validate the settings and construct the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` if:
+    /// - No User-Agent was set, or any User-Agent lacks a contact email
+    /// - The rate is zero
+    /// - The timeout is zero
+    /// - The concurrency limit is zero or too large
+    /// - The base URL is not an absolute URL
+    pub fn build(self) -> Result<Client> {
+        if self.user_agents.is_empty() {
+            return Err(Error::Custom("User-Agent is required".to_string()));
+        }
+        for user_agent in &self.user_agents {
+            validate_user_agent(user_agent)?;
+        }
+
+        if self.requests_per_second == 0 {
+            return Err(Error::Custom("Rate must be at least 1 request per second".to_string()));
+        }
+
+        if self.timeout == Some(Duration::ZERO) {
+            return Err(Error::Custom("Timeout must be greater than zero".to_string()));
+        }
+
+        if !(1..=Semaphore::MAX_PERMITS).contains(&self.max_concurrent) {
+            return Err(Error::Custom(format!(
+                "Concurrency limit must be between 1 and {}",
+                Semaphore::MAX_PERMITS
+            )));
+        }
+
+        let base_url = self.base_url.as_deref().map(parse_base_url).transpose()?;
+
+        let mut inner = ClientInner::new(self.user_agents);
+        inner.rate_limiter = RateLimiter::new(self.requests_per_second, Duration::from_secs(1));
+        inner.retry_policy = self.retry_policy;
+        inner.timeout = self.timeout;
+        inner.base_url = base_url;
+        inner.proactive_throttle = self.proactive_throttle;
+        inner.in_flight = Arc::new(Semaphore::new(self.max_concurrent));
+        #[cfg(feature = "dangerous-tls")]
+        if self.accept_invalid_certs {
+            inner.client = transport(insecure_https_connector()?);
+        }
+        if let Some(transport) = self.transport {
+            inner.client = transport;
+        }
+
+        Ok(Client { inner: Arc::new(inner) })
+    }
+}
+
+/// HTTPS connector that skips certificate and host name verification.
+#[cfg(feature = "dangerous-tls")]
+fn insecure_https_connector() -> Result<hyper_tls::HttpsConnector<hyper::client::HttpConnector>> {
+    let tls = native_tls::TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .danger_accept_invalid_hostnames(true)
+        .build()
+        .map_err(|e| Error::Custom(format!("Failed to build TLS connector: {}", e)))?;
+
+    let mut http = hyper::client::HttpConnector::new();
+    http.enforce_http(false);
+
+    Ok(hyper_tls::HttpsConnector::from((
+        http,
+        tokio_native_tls::TlsConnector::from(tls),
+    )))
+}
+
+/// Parse a mirror base URL, requiring a scheme and host.
+fn parse_base_url(base_url: &str) -> Result<Uri> {
+    let uri: Uri = base_url
+        .parse()
+        .map_err(|_| Error::Custom(format!("Invalid base URL: {}", base_url)))?;
+
+    if uri.scheme().is_none() || uri.authority().is_none() {
+        return Err(Error::Custom(format!(
+            "Base URL must include a scheme and host: {}",
+            base_url
+        )));
+    }
+
+    Ok(uri)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::net::SocketAddr;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll};
+    use tokio::net::TcpStream;
+
+    #[test]
+    fn test_build_success() {
+        let client = ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .timeout(Duration::from_secs(10))
+            .rate(5)
+            .retry(RetryPolicy::new(1))
+            .base_url("http://localhost:8080")
+            .build()
+            .expect("valid settings should build");
+
+        assert_eq!(&client.inner.user_agents[..], ["MyApp contact@example.com"]);
+        assert_eq!(client.inner.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(client.inner.retry_policy.max_attempts, 1);
+        assert!(client.inner.base_url.is_some());
+    }
+
+    #[test]
+    fn test_presets() {
+        let polite = ClientBuilder::new().user_agent("MyApp contact@example.com").polite();
+        assert_eq!(polite.requests_per_second, 5);
+        assert_eq!(polite.max_concurrent, 4);
+        assert!(polite.proactive_throttle);
+
+        let client = polite.build().unwrap();
+        assert_eq!(client.inner.in_flight.available_permits(), 4);
+        assert_eq!(client.inner.retry_policy.max_attempts, 5);
+        assert_eq!(client.inner.timeout, Some(Duration::from_secs(30)));
+
+        let aggressive = ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .aggressive();
+        assert_eq!(aggressive.requests_per_second, 10);
+        assert_eq!(aggressive.max_concurrent, 50);
+        assert!(!aggressive.proactive_throttle);
+
+        let client = aggressive.build().unwrap();
+        assert_eq!(client.inner.in_flight.available_permits(), 50);
+        assert_eq!(client.inner.retry_policy.max_attempts, 2);
+
+        // Later settings override the preset
+        let tuned = ClientBuilder::new().polite().rate(2);
+        assert_eq!(tuned.requests_per_second, 2);
+        assert_eq!(tuned.max_concurrent, 4);
+    }
+
+    #[test]
+    fn test_build_validation_failures() {
+        assert!(ClientBuilder::new().build().is_err());
+        assert!(ClientBuilder::new().user_agent("MyApp").build().is_err());
+        assert!(ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .rate(0)
+            .build()
+            .is_err());
+        assert!(ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .timeout(Duration::ZERO)
+            .build()
+            .is_err());
+        assert!(ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .max_concurrent(0)
+            .build()
+            .is_err());
+        assert!(ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .base_url("/no-host")
+            .build()
+            .is_err());
+    }
+
+    /// Connector that sends every connection to one local address.
+    #[derive(Clone)]
+    struct LocalConnector {
+        addr: SocketAddr,
+        connections: Arc<AtomicUsize>,
+    }
+
+    impl hyper::service::Service<Uri> for LocalConnector {
+        type Response = TcpStream;
+        type Error = std::io::Error;
+        type Future = Pin<Box<dyn Future<Output = std::io::Result<TcpStream>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _uri: Uri) -> Self::Future {
+            self.connections.fetch_add(1, Ordering::SeqCst);
+            Box::pin(TcpStream::connect(self.addr))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_connector_routes_to_local_server() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/edgar/data/320193/doc.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("mirrored"))
+            .mount(&server)
+            .await;
+
+        // Resolve every host to the mock server, like an internal mirror's DNS
+        let connector = LocalConnector {
+            addr: *server.address(),
+            connections: Arc::default(),
+        };
+
+        let client = ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .connector(connector.clone())
+            .build()
+            .unwrap();
+
+        let text = client
+            .get_text("http://edgar.mirror.internal/Archives/edgar/data/320193/doc.txt")
+            .await
+            .unwrap();
+
+        assert_eq!(text, "mirrored");
+        assert_eq!(connector.connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "dangerous-tls")]
+    #[tokio::test]
+    async fn test_dangerous_accept_invalid_certs_reaches_self_signed_server() {
+        use hyper::service::service_fn;
+        use hyper::{Body, Response};
+        use std::convert::Infallible;
+
+        let cert = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/self_signed_cert.pem"));
+        let key = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/self_signed_key.pem"));
+        let identity = native_tls::Identity::from_pkcs8(cert, key).unwrap();
+        let acceptor = tokio_native_tls::TlsAcceptor::from(native_tls::TlsAcceptor::new(identity).unwrap());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let Ok(stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let service =
+                        service_fn(|_| async { Ok::<_, Infallible>(Response::new(Body::from("secure mirror"))) });
+                    let _ = hyper::server::conn::Http::new().serve_connection(stream, service).await;
+                });
+            }
+        });
+
+        let builder = ClientBuilder::new()
+            .user_agent("MyApp contact@example.com")
+            .base_url(format!("https://localhost:{}", addr.port()))
+            .retry(RetryPolicy::new(1));
+        let url = "https://www.sec.gov/Archives/edgar/data/320193/doc.txt";
+
+        let strict = builder.clone().build().unwrap();
+        assert!(strict.get_text(url).await.is_err());
+
+        let insecure = builder.dangerous_accept_invalid_certs(true).build().unwrap();
+        assert_eq!(insecure.get_text(url).await.unwrap(), "secure mirror");
+    }
+}
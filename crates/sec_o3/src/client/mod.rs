@@ -5,6 +5,7 @@
 ///
 /// # Submodules
 ///
+/// * [`builder`] - Typed builder for configuring a client
 /// * [`rate_limit`] - Rate limiting to comply with SEC API limits
 /// * [`retry`] - Retry logic with exponential backoff
 /// * [`validation`] - Request and response validation
@@ -32,23 +33,49 @@
 /// }
 /// ```
 ///
+pub mod builder;
 pub mod rate_limit;
 pub mod retry;
 pub mod validation;
 use async_compression::tokio::bufread::{GzipDecoder, ZlibDecoder};
+pub use builder::ClientBuilder;
+use futures::future::BoxFuture;
 use futures::TryStreamExt;
-use hyper::client::HttpConnector;
+use hyper::client::connect::Connect;
 use hyper::{Body, Method, Request, Response, StatusCode, Uri};
 use hyper_tls::HttpsConnector;
-use std::path::Path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::fs;
-use tokio::io::{AsyncReadExt, BufReader};
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::time::Instant;
+pub use validation::contact_email;
 
 use crate::errors::{Error, Result};
+use crate::filings::Filing;
+use crate::parse::html::{parse_html, parse_text, Document};
+use crate::utils::{url_kind, UrlKind};
 use rate_limit::RateLimiter;
 use retry::RetryPolicy;
+use validation::validate_response_headers;
+
+/// Accept header for data API (JSON) requests.
+const ACCEPT_JSON: &str = "application/json";
+
+/// Accept header for documents, so the SEC serves the file rather than a JSON error.
+const ACCEPT_ANY: &str = "*/*";
+
+/// Accept header for filing documents, preferring HTML over plain text.
+const ACCEPT_DOCUMENT: &str = "text/html, application/xhtml+xml, text/plain;q=0.9, */*;q=0.8";
+
+/// Default limit on requests in flight at once.
+const DEFAULT_MAX_CONCURRENT: usize = 20;
+
+/// Pause before the next request after the SEC reports a nearly exhausted rate limit.
+const PROACTIVE_THROTTLE_DELAY: Duration = Duration::from_secs(1);
 
 /// SEC API client with rate limiting, retry support, and async decompression.
 #[derive(Clone)]
@@ -56,73 +83,218 @@ pub struct Client {
     inner: Arc<ClientInner>,
 }
 
+#[derive(Clone)]
 struct ClientInner {
-    client: hyper::Client<HttpsConnector<HttpConnector>>,
+    client: Arc<dyn Transport>,
     rate_limiter: RateLimiter,
     retry_policy: RetryPolicy,
-    user_agent: String,
+    /// User-Agents used in turn, never empty
+    user_agents: Arc<[String]>,
+    next_user_agent: Arc<AtomicUsize>,
+    timeout: Option<Duration>,
+    base_url: Option<Uri>,
+    proactive_throttle: bool,
+    /// No request starts before this instant (set by proactive throttling)
+    throttle_until: Arc<std::sync::Mutex<Option<Instant>>>,
+    /// One permit per request allowed in flight
+    in_flight: Arc<tokio::sync::Semaphore>,
+}
+
+impl ClientInner {
+    /// Default settings for the given User-Agents.
+    fn new(user_agents: Vec<String>) -> Self {
+        Self {
+            client: transport(HttpsConnector::new()),
+            rate_limiter: RateLimiter::new(10, Duration::from_secs(1)),
+            retry_policy: RetryPolicy::default(),
+            user_agents: user_agents.into(),
+            next_user_agent: Arc::new(AtomicUsize::new(0)),
+            timeout: None,
+            base_url: None,
+            proactive_throttle: false,
+            throttle_until: Arc::new(std::sync::Mutex::new(None)),
+            in_flight: Arc::new(tokio::sync::Semaphore::new(DEFAULT_MAX_CONCURRENT)),
+        }
+    }
+
+    /// Hold back requests for `delay` from now.
+    fn throttle_for(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut throttle_until = self.throttle_until.lock().unwrap_or_else(|e| e.into_inner());
+        *throttle_until = Some(throttle_until.map_or(until, |current| current.max(until)));
+    }
+
+    /// Wait out any proactive throttle delay.
+    async fn wait_for_throttle(&self) {
+        let until = *self.throttle_until.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(until) = until {
+            tokio::time::sleep_until(until).await;
+        }
+    }
+
+    /// User-Agent for the next request, cycling through all configured ones.
+    fn user_agent(&self) -> &str {
+        let next = self.next_user_agent.fetch_add(1, Ordering::Relaxed);
+        &self.user_agents[next % self.user_agents.len()]
+    }
+}
+
+/// Cache-related headers of a response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// `ETag` header, quotes included (e.g., `"5f3c-63a1"`)
+    pub etag: Option<String>,
+    /// `Last-Modified` header (e.g., "Fri, 03 Nov 2023 10:04:26 GMT")
+    pub last_modified: Option<String>,
+    /// `Content-Length` header, the size of the body as sent (possibly compressed)
+    pub content_length: Option<u64>,
+}
+
+impl ResponseMeta {
+    fn from_headers(headers: &hyper::HeaderMap) -> Self {
+        let header = |name: hyper::header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Self {
+            etag: header(hyper::header::ETAG),
+            last_modified: header(hyper::header::LAST_MODIFIED),
+            content_length: header(hyper::header::CONTENT_LENGTH).and_then(|len| len.parse().ok()),
+        }
+    }
+}
+
+/// Type-erased HTTP transport, so custom connectors don't change [`Client`]'s type.
+trait Transport: Send + Sync {
+    fn send(&self, req: Request<Body>) -> BoxFuture<'static, hyper::Result<Response<Body>>>;
+}
+
+impl<C> Transport for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send(&self, req: Request<Body>) -> BoxFuture<'static, hyper::Result<Response<Body>>> {
+        Box::pin(self.request(req))
+    }
+}
+
+/// Build a pooled hyper client over `connector` with the SEC connection settings.
+fn transport<C>(connector: C) -> Arc<dyn Transport>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    let client = hyper::Client::builder()
+        .pool_idle_timeout(Duration::from_secs(30))
+        .http2_keep_alive_interval(Some(Duration::from_secs(15)))
+        .http2_keep_alive_timeout(Duration::from_secs(5))
+        .build::<_, Body>(connector);
+
+    Arc::new(client)
 }
 
 impl Client {
     /// Create a new SEC client with default settings.
     pub fn new(contact_name: &str, contact_email: &str) -> Self {
-        let https = HttpsConnector::new();
-        let client = hyper::Client::builder()
-            .pool_idle_timeout(Duration::from_secs(30))
-            .http2_keep_alive_interval(Some(Duration::from_secs(15)))
-            .http2_keep_alive_timeout(Duration::from_secs(5))
-            .build::<_, Body>(https);
-
         Self {
-            inner: Arc::new(ClientInner {
-                client,
-                rate_limiter: RateLimiter::new(10, Duration::from_secs(1)),
-                retry_policy: RetryPolicy::default(),
-                user_agent: format!("{} {}", contact_name, contact_email),
-            }),
+            inner: Arc::new(ClientInner::new(vec![format!("{} {}", contact_name, contact_email)])),
         }
     }
 
+    /// Create a [`ClientBuilder`] for configuring rate, retries, timeout, and more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec_o3::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .user_agent("MyApp contact@example.com")
+    ///     .rate(5)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
     /// Create client from USER_AGENT environment variable.
     pub fn from_env() -> Result<Self> {
         let user_agent = std::env::var("USER_AGENT").map_err(|_| Error::Custom("USER_AGENT not set".into()))?;
 
-        if !user_agent.contains('@') {
-            return Err(Error::Custom(
-                "USER_AGENT must include email per SEC requirements".into(),
-            ));
-        }
+        Self::builder().user_agent(user_agent).build()
+    }
 
-        let https = HttpsConnector::new();
-        let client = hyper::Client::builder()
-            .pool_idle_timeout(Duration::from_secs(30))
-            .http2_keep_alive_interval(Some(Duration::from_secs(15)))
-            .http2_keep_alive_timeout(Duration::from_secs(5))
-            .build::<_, Body>(https);
+    /// Create client from SEC_APP_NAME and SEC_CONTACT_EMAIL environment variables.
+    ///
+    /// The User-Agent is composed as `"{SEC_APP_NAME} {SEC_CONTACT_EMAIL}"`
+    /// after validating the email. Falls back to [`Client::from_env`] when
+    /// either variable is unset.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sec_o3::Client;
+    ///
+    /// // SEC_APP_NAME="MyApp" SEC_CONTACT_EMAIL="contact@example.com"
+    /// let client = Client::from_env_parts()?;
+    /// # Ok::<(), sec_o3::Error>(())
+    /// ```
+    pub fn from_env_parts() -> Result<Self> {
+        Self::from_env_parts_with(|name| std::env::var(name).ok())
+    }
 
-        Ok(Self {
-            inner: Arc::new(ClientInner {
-                client,
-                rate_limiter: RateLimiter::new(10, Duration::from_secs(1)),
-                retry_policy: RetryPolicy::default(),
-                user_agent,
-            }),
-        })
+    /// [`Client::from_env_parts`] reading variables through `var`.
+    fn from_env_parts_with(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let user_agent = match (var("SEC_APP_NAME"), var("SEC_CONTACT_EMAIL")) {
+            (Some(app_name), Some(email)) => {
+                let (app_name, email) = (app_name.trim(), email.trim());
+                if app_name.is_empty() {
+                    return Err(Error::Custom("SEC_APP_NAME is empty".into()));
+                }
+                validation::validate_email(email)?;
+                format!("{} {}", app_name, email)
+            }
+            _ => var("USER_AGENT").ok_or_else(|| {
+                Error::Custom("Neither SEC_APP_NAME and SEC_CONTACT_EMAIL nor USER_AGENT are set".into())
+            })?,
+        };
+
+        Self::builder().user_agent(user_agent).build()
+    }
+
+    /// Contact email of the configured User-Agent.
+    ///
+    /// With several rotating User-Agents, the email of the first one.
+    pub fn contact_email(&self) -> Option<String> {
+        contact_email(&self.inner.user_agents[0])
     }
 
     /// Make a GET request with automatic retries and rate limiting.
+    ///
+    /// The `Accept` header follows the URL's [`url_kind`]: JSON for the
+    /// data and full-text search APIs, `*/*` otherwise, so archives serve
+    /// the file itself rather than an error page.
     pub async fn get(&self, url: &str) -> Result<Response<Body>> {
-        let uri: Uri = url
-            .parse()
-            .map_err(|_| Error::Custom(format!("Invalid URL: {}", url)))?;
-        self.request(Method::GET, uri).await
+        self.get_with_accept(url, accept_for(url), &self.inner.retry_policy)
+            .await
+    }
+
+    /// GET request with a retry policy that overrides the client default for this call.
+    ///
+    /// Useful for best-effort bulk scans (`RetryPolicy::new(1)`) or for
+    /// interactive lookups that should retry harder than the default.
+    pub async fn get_with_policy(&self, url: &str, policy: &RetryPolicy) -> Result<Response<Body>> {
+        self.get_with_accept(url, accept_for(url), policy).await
     }
 
     /// Get response body as decompressed bytes.
     /// Automatically handles gzip and deflate based on Content-Encoding header.
     pub async fn get_bytes(&self, url: &str) -> Result<bytes::Bytes> {
         let response = self.get(url).await?;
-        self.decode_response(response).await
+        self.decode_response(response, url).await
     }
 
     /// Get response body as UTF-8 string with automatic decompression.
@@ -132,74 +304,215 @@ impl Client {
     }
 
     /// Fetch and deserialize JSON with automatic decompression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` if the response body is empty.
     pub async fn get_json<T>(&self, url: &str) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let bytes = self.get_bytes(url).await?;
+        self.get_json_with_policy(url, &self.inner.retry_policy).await
+    }
+
+    /// Fetch and deserialize JSON using a retry policy for this call only.
+    ///
+    /// See [`Client::get_with_policy`].
+    pub async fn get_json_with_policy<T>(&self, url: &str, policy: &RetryPolicy) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let bytes = self.get_json_body(url, policy).await?;
         serde_json::from_slice(&bytes).map_err(Error::JsonError)
     }
 
+    /// Fetch and deserialize JSON along with cache-related response headers.
+    ///
+    /// Useful for building an incremental cache keyed on `ETag` or
+    /// `Last-Modified`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sec_o3::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> sec_o3::Result<()> {
+    ///     let client = Client::new("MyApp", "contact@example.com");
+    ///     let url = "https://data.sec.gov/submissions/CIK0000320193.json";
+    ///     let (_json, meta): (serde_json::Value, _) = client.get_json_with_meta(url).await?;
+    ///     println!("ETag: {:?}", meta.etag);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_json_with_meta<T>(&self, url: &str) -> Result<(T, ResponseMeta)>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let (bytes, meta) = self.get_json_body_with_meta(url, &self.inner.retry_policy).await?;
+        let value = serde_json::from_slice(&bytes).map_err(Error::JsonError)?;
+        Ok((value, meta))
+    }
+
+    /// Fetch the raw body of a JSON endpoint, rejecting empty responses.
+    pub(crate) async fn get_json_body(&self, url: &str, policy: &RetryPolicy) -> Result<bytes::Bytes> {
+        let (bytes, _) = self.get_json_body_with_meta(url, policy).await?;
+        Ok(bytes)
+    }
+
+    async fn get_json_body_with_meta(&self, url: &str, policy: &RetryPolicy) -> Result<(bytes::Bytes, ResponseMeta)> {
+        let response = self.get_with_accept(url, ACCEPT_JSON, policy).await?;
+        let meta = ResponseMeta::from_headers(response.headers());
+        let bytes = self.decode_response(response, url).await?;
+
+        // An empty body would otherwise surface as "EOF while parsing a value"
+        if bytes.iter().all(u8::is_ascii_whitespace) {
+            return Err(Error::Custom(format!("Empty response body: {}", url)));
+        }
+        check_html_error_page(&bytes, url)?;
+
+        Ok((bytes, meta))
+    }
+
+    /// Default retry policy of this client.
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        &self.inner.retry_policy
+    }
+
+    /// Download a filing's primary document and parse it into a [`Document`].
+    ///
+    /// The document URL comes from [`Filing::best_document_url`]. The format
+    /// is taken from the file extension (`.htm`/`.html` or `.txt`), then the
+    /// `Content-Type` header, and finally from whether the body looks like
+    /// markup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` if the document is not valid UTF-8.
+    pub async fn fetch_and_parse_primary(&self, filing: &Filing) -> Result<Document> {
+        let url = filing.best_document_url(self).await?;
+
+        let response = self
+            .get_with_accept(&url, ACCEPT_DOCUMENT, &self.inner.retry_policy)
+            .await?;
+        let content_type = response
+            .headers()
+            .get(hyper::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_ascii_lowercase);
+
+        let bytes = self.decode_response(response, &url).await?;
+        let body = std::str::from_utf8(&bytes).map_err(|e| Error::Custom(format!("Invalid UTF-8: {}", e)))?;
+
+        let path = url.split(['?', '#']).next().unwrap_or_default().to_ascii_lowercase();
+        let is_html = if path.ends_with(".htm") || path.ends_with(".html") || path.ends_with(".xhtml") {
+            true
+        } else if path.ends_with(".txt") {
+            false
+        } else {
+            match content_type.as_deref() {
+                Some(content_type) if content_type.contains("html") => true,
+                Some(content_type) if content_type.starts_with("text/plain") => false,
+                _ => body.trim_start().starts_with('<'),
+            }
+        };
+
+        if is_html {
+            parse_html(body)
+        } else {
+            Ok(parse_text(body))
+        }
+    }
+
     /// Download text file with UTF-8 validation and automatic decompression.
+    ///
+    /// The file is written atomically (see [`Client::download_bytes`]).
     pub async fn download_text(&self, url: &str, path: impl AsRef<Path>) -> Result<()> {
         let bytes = self.get_bytes(url).await?;
 
         let text = std::str::from_utf8(&bytes).map_err(|e| Error::Custom(format!("Invalid UTF-8: {}", e)))?;
 
-        fs::write(path, text).await.map_err(Error::IoError)?;
-        Ok(())
+        write_atomic(path.as_ref(), text.as_bytes()).await
     }
 
     /// Download raw bytes with automatic decompression.
+    ///
+    /// The file is written to `<path>.part` and renamed into place once
+    /// complete, so an interrupted download never leaves a truncated file
+    /// at `path`.
     pub async fn download_bytes(&self, url: &str, path: impl AsRef<Path>) -> Result<()> {
         let bytes = self.get_bytes(url).await?;
-        fs::write(path, &bytes).await.map_err(Error::IoError)?;
-        Ok(())
+        write_atomic(path.as_ref(), &bytes).await
     }
 
     /// Stream large file directly to disk with async decompression.
+    ///
+    /// Like [`Client::download_bytes`], data goes to `<path>.part` first, and
+    /// the partial file is removed if the download fails.
     pub async fn download_streaming(&self, url: &str, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let part = partial_path(path);
+
+        let result = async {
+            {
+                let mut file = fs::File::create(&part).await.map_err(Error::IoError)?;
+                self.stream_to(url, &mut file).await?;
+            }
+            fs::rename(&part, path).await.map_err(Error::IoError)
+        }
+        .await;
+
+        if result.is_err() {
+            let _ = fs::remove_file(&part).await;
+        }
+        result
+    }
+
+    /// Stream a response body into any async writer with decompression.
+    ///
+    /// Useful for piping downloads into compressors, archive writers, or
+    /// object storage uploads without an intermediate file. The sink is
+    /// flushed before returning.
+    ///
+    /// # Returns
+    ///
+    /// The number of decompressed bytes written to `sink`.
+    pub async fn stream_to<W>(&self, url: &str, sink: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
         let response = self.get(url).await?;
 
-        let encoding = response
-            .headers()
-            .get(hyper::header::CONTENT_ENCODING)
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_lowercase());
+        let encoding = content_encoding(&response, url);
 
         let body = response.into_body();
         let mut reader = tokio_util::io::StreamReader::new(body.map_err(std::io::Error::other));
 
-        let mut file = fs::File::create(path).await.map_err(Error::IoError)?;
-
-        match encoding.as_deref() {
+        let written = match encoding.as_deref() {
             Some("gzip") => {
                 let mut decoder = GzipDecoder::new(BufReader::new(reader));
-                tokio::io::copy(&mut decoder, &mut file)
+                tokio::io::copy(&mut decoder, sink)
                     .await
-                    .map_err(|e| Error::Custom(format!("Gzip streaming failed: {}", e)))?;
+                    .map_err(|e| Error::Custom(format!("Gzip streaming failed: {}", e)))?
             }
             Some("deflate") => {
                 let mut decoder = ZlibDecoder::new(BufReader::new(reader));
-                tokio::io::copy(&mut decoder, &mut file)
+                tokio::io::copy(&mut decoder, sink)
                     .await
-                    .map_err(|e| Error::Custom(format!("Deflate streaming failed: {}", e)))?;
+                    .map_err(|e| Error::Custom(format!("Deflate streaming failed: {}", e)))?
             }
-            _ => {
-                tokio::io::copy(&mut reader, &mut file).await.map_err(Error::IoError)?;
-            }
-        }
+            _ => tokio::io::copy(&mut reader, sink).await.map_err(Error::IoError)?,
+        };
 
-        Ok(())
+        sink.flush().await.map_err(Error::IoError)?;
+        Ok(written)
     }
 
     /// Asynchronously decodes response body based on Content-Encoding header.
-    async fn decode_response(&self, response: Response<Body>) -> Result<bytes::Bytes> {
-        let encoding = response
-            .headers()
-            .get(hyper::header::CONTENT_ENCODING)
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_lowercase());
+    ///
+    /// Falls back to the URL extension when the header is absent (see [`content_encoding`]).
+    async fn decode_response(&self, response: Response<Body>, url: &str) -> Result<bytes::Bytes> {
+        let encoding = content_encoding(&response, url);
 
         let body = hyper::body::to_bytes(response.into_body())
             .await
@@ -232,42 +545,768 @@ impl Client {
         }
     }
 
+    /// GET request with an explicit `Accept` header.
+    async fn get_with_accept(&self, url: &str, accept: &'static str, policy: &RetryPolicy) -> Result<Response<Body>> {
+        let uri: Uri = url
+            .parse()
+            .map_err(|_| Error::Custom(format!("Invalid URL: {}", url)))?;
+        self.request(Method::GET, uri, accept, policy).await
+    }
+
+    /// Rewrite a request URL onto the configured base URL, if any.
+    fn resolve_uri(&self, uri: Uri) -> Result<Uri> {
+        let Some(base) = &self.inner.base_url else {
+            return Ok(uri);
+        };
+
+        let prefix = base.path().trim_end_matches('/');
+        let path_and_query = uri.path_and_query().map(|pq| pq.as_str()).unwrap_or("/");
+
+        Uri::builder()
+            .scheme(base.scheme_str().unwrap_or("https"))
+            .authority(base.authority().map(|a| a.as_str()).unwrap_or_default())
+            .path_and_query(format!("{}{}", prefix, path_and_query))
+            .build()
+            .map_err(Error::HttpError)
+    }
+
     /// Internal request method with retry logic.
-    async fn request(&self, method: Method, uri: Uri) -> Result<Response<Body>> {
+    ///
+    /// Only failures that [`Error::is_retryable`] accepts are retried.
+    /// Holds one of the client's in-flight permits until all attempts fail
+    /// or, on success, until the response body is read to the end or
+    /// dropped.
+    async fn request(
+        &self,
+        method: Method,
+        uri: Uri,
+        accept: &'static str,
+        policy: &RetryPolicy,
+    ) -> Result<Response<Body>> {
+        let uri = self.resolve_uri(uri)?;
+        let permit = Arc::clone(&self.inner.in_flight)
+            .acquire_owned()
+            .await
+            .map_err(|e| Error::Custom(format!("Request limiter closed: {}", e)))?;
+        self.inner.wait_for_throttle().await;
         self.inner.rate_limiter.wait().await;
 
         let inner = Arc::clone(&self.inner);
 
-        self.inner
-            .retry_policy
-            .execute(|| {
-                let uri = uri.clone();
-                let method = method.clone();
-                let inner = Arc::clone(&inner);
-
-                Box::pin(async move {
-                    let req = Request::builder()
-                        .method(method)
-                        .uri(&uri)
-                        .header("User-Agent", &inner.user_agent)
-                        .header("Accept", "application/json")
-                        .header("Accept-Encoding", "gzip, deflate")
-                        .header("Host", uri.host().unwrap_or("data.sec.gov"))
-                        .body(Body::empty())
-                        .map_err(Error::HttpError)?;
-
-                    let response = inner.client.request(req).await.map_err(Error::HyperError)?;
-
-                    match response.status() {
-                        StatusCode::OK => Ok(response),
-                        StatusCode::TOO_MANY_REQUESTS => {
-                            Err(Error::RateLimitExceeded("SEC rate limit exceeded".into()))
-                        }
-                        StatusCode::NOT_FOUND => Err(Error::NotFound(format!("Not found: {}", uri))),
-                        status => Err(Error::InvalidStatus(status)),
-                    }
-                })
+        let response = policy
+            .execute_if(
+                || Box::pin(send_once(Arc::clone(&inner), method.clone(), uri.clone(), accept)),
+                Error::is_retryable,
+            )
+            .await?;
+
+        // The connection stays busy while the body streams, so the body owns the permit
+        let (parts, body) = response.into_parts();
+        let body = Body::wrap_stream(body.map_ok(move |chunk| {
+            let _permit = &permit;
+            chunk
+        }));
+        Ok(Response::from_parts(parts, body))
+    }
+}
+
+/// Reject HTML error pages served with a 200 status instead of JSON.
+///
+/// Unknown CIKs sometimes return an HTML "No matching CIK" page, which is
+/// reported as `Error::NotFound`. Any other HTML body, typically a
+/// maintenance page, is an `Error::Custom` rather than a serde error about
+/// an unexpected `<`.
+fn check_html_error_page(body: &[u8], url: &str) -> Result<()> {
+    let body = body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(body);
+    if body.iter().find(|b| !b.is_ascii_whitespace()) != Some(&b'<') {
+        return Ok(());
+    }
+
+    let page = String::from_utf8_lossy(body).to_lowercase();
+    if page.contains("no matching") {
+        return Err(Error::NotFound(format!("No matching CIK: {}", url)));
+    }
+
+    Err(Error::Custom(format!(
+        "Received HTML where JSON expected, SEC may be down: {}",
+        url
+    )))
+}
+
+/// Default `Accept` header for a URL.
+fn accept_for(url: &str) -> &'static str {
+    match url_kind(url) {
+        UrlKind::DataApi | UrlKind::FullTextSearch => ACCEPT_JSON,
+        UrlKind::Archive | UrlKind::Other => ACCEPT_ANY,
+    }
+}
+
+/// Temporary path a download is written to before being renamed to `path`.
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".part");
+    path.with_file_name(name)
+}
+
+/// Write `contents` to `path` via a `.part` file, leaving no partial file on error.
+async fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let part = partial_path(path);
+
+    let result = async {
+        fs::write(&part, contents).await?;
+        fs::rename(&part, path).await
+    }
+    .await;
+
+    if result.is_err() {
+        let _ = fs::remove_file(&part).await;
+    }
+    result.map_err(Error::IoError)
+}
+
+/// Content encoding of a response body, lowercased.
+///
+/// The `Content-Encoding` header takes precedence. Without it, archive
+/// files named `*.gz` are treated as gzip, since EDGAR serves some of them
+/// compressed without declaring an encoding.
+fn content_encoding(response: &Response<Body>, url: &str) -> Option<String> {
+    if let Some(encoding) = response
+        .headers()
+        .get(hyper::header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+    {
+        return Some(encoding.to_lowercase());
+    }
+
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.to_ascii_lowercase().ends_with(".gz").then(|| "gzip".to_string())
+}
+
+/// Perform a single request attempt and map error statuses.
+async fn send_once(inner: Arc<ClientInner>, method: Method, uri: Uri, accept: &'static str) -> Result<Response<Body>> {
+    let req = Request::builder()
+        .method(method)
+        .uri(&uri)
+        .header("User-Agent", inner.user_agent())
+        .header("Accept", accept)
+        .header("Accept-Encoding", "gzip, deflate")
+        .header("Host", uri.host().unwrap_or("data.sec.gov"))
+        .body(Body::empty())
+        .map_err(Error::HttpError)?;
+
+    let response = match inner.timeout {
+        Some(timeout) => tokio::time::timeout(timeout, inner.client.send(req))
+            .await
+            .map_err(|_| Error::Timeout(timeout))?,
+        None => inner.client.send(req).await,
+    }
+    .map_err(Error::HyperError)?;
+
+    if inner.proactive_throttle && validate_response_headers(response.headers()).is_rate_limit_low() {
+        inner.throttle_for(PROACTIVE_THROTTLE_DELAY);
+    }
+
+    match response.status() {
+        StatusCode::OK => Ok(response),
+        StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimitExceeded("SEC rate limit exceeded".into())),
+        StatusCode::FORBIDDEN => Err(Error::Forbidden(uri.to_string())),
+        StatusCode::NOT_FOUND => Err(Error::NotFound(format!("Not found: {}", uri))),
+        status if status.is_redirection() => match response.headers().get(hyper::header::LOCATION) {
+            Some(location) => Err(Error::Redirect(
+                String::from_utf8_lossy(location.as_bytes()).into_owned(),
+            )),
+            None => Err(Error::InvalidStatus(status)),
+        },
+        status => Err(Error::InvalidStatus(status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::sample_filing;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Client pointed at a mock server, without retries.
+    fn test_client(server: &MockServer) -> Client {
+        Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .retry(RetryPolicy::new(1))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_accept_header_differs_between_json_and_text() {
+        let server = MockServer::start().await;
+        Mock::given(path("/api/data.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"ok":true}"#))
+            .mount(&server)
+            .await;
+        Mock::given(path("/Archives/doc.htm"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let _: serde_json::Value = client.get_json("https://data.sec.gov/api/data.json").await.unwrap();
+        client.get_text("https://www.sec.gov/Archives/doc.htm").await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let accept = |p: &str| {
+            let request = requests.iter().find(|r| r.url.path() == p).unwrap();
+            request.headers.get("accept").unwrap().to_str().unwrap().to_string()
+        };
+
+        assert_eq!(accept("/api/data.json"), "application/json");
+        assert_eq!(accept("/Archives/doc.htm"), "*/*");
+    }
+
+    #[tokio::test]
+    async fn test_get_picks_accept_header_from_url_kind() {
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        client
+            .get_text("https://data.sec.gov/submissions/CIK0000320193.json")
+            .await
+            .unwrap();
+        client
+            .get_text("https://efts.sec.gov/LATEST/search-index?q=x")
+            .await
+            .unwrap();
+        client
+            .get_text("https://www.sec.gov/Archives/edgar/data/320193/a.htm")
+            .await
+            .unwrap();
+        client
+            .get_text("https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent")
+            .await
+            .unwrap();
+
+        let accepts: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| request.headers["accept"].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(accepts, [ACCEPT_JSON, ACCEPT_JSON, ACCEPT_ANY, ACCEPT_ANY]);
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_returns_hint_without_retry() {
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .retry(RetryPolicy {
+                initial_delay: Duration::from_millis(1),
+                ..RetryPolicy::new(3)
             })
+            .build()
+            .unwrap();
+
+        let err = client
+            .get("https://www.sec.gov/cgi-bin/browse-edgar")
             .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Forbidden(_)));
+        assert!(err.to_string().contains("User-Agent"));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_to_in_memory_buffer() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let body = "<SEC-DOCUMENT>".repeat(1000);
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(body.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/plain.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body.clone()))
+            .mount(&server)
+            .await;
+        Mock::given(path("/Archives/gzipped.txt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Encoding", "gzip")
+                    .set_body_bytes(compressed),
+            )
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+
+        for url in [
+            "https://www.sec.gov/Archives/plain.txt",
+            "https://www.sec.gov/Archives/gzipped.txt",
+        ] {
+            let mut sink = Vec::new();
+            let written = client.stream_to(url, &mut sink).await.unwrap();
+            assert_eq!(written, body.len() as u64);
+            assert_eq!(sink, body.as_bytes());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gz_url_without_encoding_header_is_decompressed() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let body = "company.idx contents\n".repeat(50);
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(body.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/edgar/full-index/2023/QTR4/company.gz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(compressed))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let url = "https://www.sec.gov/Archives/edgar/full-index/2023/QTR4/company.gz";
+
+        assert_eq!(client.get_text(url).await.unwrap(), body);
+
+        let mut sink = Vec::new();
+        client.stream_to(url, &mut sink).await.unwrap();
+        assert_eq!(sink, body.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_get_json_empty_body() {
+        let server = MockServer::start().await;
+        Mock::given(path("/api/xbrl/companyconcept/CIK0000000001/us-gaap/Assets.json"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let err = client
+            .get_json::<serde_json::Value>(
+                "https://data.sec.gov/api/xbrl/companyconcept/CIK0000000001/us-gaap/Assets.json",
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Custom(_)));
+        assert!(err.to_string().starts_with("Empty response body"));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_html_body() {
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000320193.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("\n<!DOCTYPE html><html><body><h1>Scheduled maintenance</h1></body></html>"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server);
+        let err = client
+            .get_json::<serde_json::Value>("https://data.sec.gov/submissions/CIK0000320193.json")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Custom(_)));
+        assert!(err
+            .to_string()
+            .starts_with("Received HTML where JSON expected, SEC may be down"));
+    }
+
+    #[tokio::test]
+    async fn test_retries_only_transient_failures() {
+        let server = MockServer::start().await;
+        Mock::given(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/flaky"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("third time"))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .retry(RetryPolicy {
+                initial_delay: Duration::from_millis(1),
+                ..RetryPolicy::new(3)
+            })
+            .build()
+            .unwrap();
+
+        let text = client.get_text("https://www.sec.gov/flaky").await.unwrap();
+        assert_eq!(text, "third time");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+        let result = client.get_text("https://www.sec.gov/missing").await;
+        assert!(matches!(result, Err(Error::NotFound(_))));
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_per_call_retry_policy_overrides_default() {
+        let server = MockServer::start().await;
+        Mock::given(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .retry(RetryPolicy {
+                initial_delay: Duration::from_millis(1),
+                ..RetryPolicy::new(3)
+            })
+            .build()
+            .unwrap();
+
+        let result = client
+            .get_with_policy("https://www.sec.gov/flaky", &RetryPolicy::new(1))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidStatus(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+        let result = client.get("https://www.sec.gov/flaky").await;
+        assert!(result.is_err());
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_parse_primary_detects_format() {
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/edgar/data/1234/000123409000000001/form10k.htm"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<html><head><title>form10k</title></head><body><p>FORM 10-K</p><p>Annual report</p></body></html>",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(path("/Archives/edgar/data/1234/000123409000000002/form10k.txt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "text/html")
+                    .set_body_string("FORM 10-K\n\n  <Annual   report>  \n"),
+            )
+            .mount(&server)
+            .await;
+        let client = test_client(&server);
+
+        let html = client
+            .fetch_and_parse_primary(&Filing {
+                cik: "1234".to_string(),
+                accession_number: "0001234090-00-000001".to_string(),
+                primary_document: "form10k.htm".to_string(),
+                ..sample_filing()
+            })
+            .await
+            .unwrap();
+        assert_eq!(html.title.as_deref(), Some("form10k"));
+        assert_eq!(html.text, "FORM 10-K\nAnnual report");
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].headers["accept"], ACCEPT_DOCUMENT);
+
+        // The extension wins over a mislabeled content type
+        let text = client
+            .fetch_and_parse_primary(&Filing {
+                cik: "1234".to_string(),
+                accession_number: "0001234090-00-000002".to_string(),
+                primary_document: "form10k.txt".to_string(),
+                ..sample_filing()
+            })
+            .await
+            .unwrap();
+        assert_eq!(text.title, None);
+        assert_eq!(text.form_type.as_deref(), Some("10-K"));
+        assert_eq!(text.text, "FORM 10-K\n<Annual report>");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires network access to sec.gov"]
+    async fn test_fetch_and_parse_primary_live_10k() {
+        let client = Client::new("sec_o3", "test@example.com");
+        let doc = client.fetch_and_parse_primary(&sample_filing()).await.unwrap();
+        assert_eq!(doc.form_type.as_deref(), Some("10-K"));
+        assert!(doc.text.contains("Apple Inc."));
+    }
+
+    #[tokio::test]
+    async fn test_user_agents_rotate_per_request() {
+        let server = MockServer::start().await;
+        Mock::given(path("/doc.htm"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let agents = ["CrawlA a@example.com", "CrawlB b@example.com", "CrawlC c@example.com"];
+        let client = Client::builder()
+            .user_agents(agents)
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+
+        for _ in 0..4 {
+            client.get("https://www.sec.gov/doc.htm").await.unwrap();
+        }
+
+        let sent: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| request.headers["user-agent"].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(sent, [agents[0], agents[1], agents[2], agents[0]]);
+
+        let invalid = Client::builder()
+            .user_agents(["CrawlA a@example.com", "CrawlB"])
+            .build();
+        assert!(invalid.is_err());
+    }
+
+    /// Transport that records the peak number of concurrent requests.
+    #[derive(Default)]
+    struct InFlightTransport {
+        current: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    impl Transport for Arc<InFlightTransport> {
+        fn send(&self, _req: Request<Body>) -> BoxFuture<'static, hyper::Result<Response<Body>>> {
+            let counts = Arc::clone(self);
+            Box::pin(async move {
+                let current = counts.current.fetch_add(1, Ordering::SeqCst) + 1;
+                counts.peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                counts.current.fetch_sub(1, Ordering::SeqCst);
+                Ok(Response::new(Body::from("ok")))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_limits_requests_in_flight() {
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .rate(10_000)
+            .max_concurrent(3)
+            .build()
+            .unwrap();
+        let counts = Arc::new(InFlightTransport::default());
+        let client = Client {
+            inner: Arc::new(ClientInner {
+                client: Arc::new(Arc::clone(&counts)),
+                ..(*client.inner).clone()
+            }),
+        };
+
+        let requests = (0..30).map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move { client.get_text(&format!("https://www.sec.gov/doc{}.htm", i)).await })
+        });
+        for result in futures::future::join_all(requests).await {
+            assert_eq!(result.unwrap().unwrap(), "ok");
+        }
+
+        assert_eq!(counts.peak.load(Ordering::SeqCst), 3);
+        assert_eq!(counts.current.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_counts_unread_bodies() {
+        let server = MockServer::start().await;
+        Mock::given(path("/doc.htm"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&server)
+            .await;
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .max_concurrent(1)
+            .build()
+            .unwrap();
+
+        let response = client.get("https://www.sec.gov/doc.htm").await.unwrap();
+        assert_eq!(client.inner.in_flight.available_permits(), 0);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(&body[..], b"ok");
+        assert_eq!(client.inner.in_flight.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_proactive_throttle_delays_after_low_remaining() {
+        let server = MockServer::start().await;
+        Mock::given(path("/low"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-ratelimit-remaining", "1"))
+            .mount(&server)
+            .await;
+
+        let throttled = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .proactive_throttle(true)
+            .build()
+            .unwrap();
+        throttled.get("https://www.sec.gov/low").await.unwrap();
+        let started = Instant::now();
+        throttled.get("https://www.sec.gov/low").await.unwrap();
+        assert!(started.elapsed() >= PROACTIVE_THROTTLE_DELAY - Duration::from_millis(50));
+
+        // Off by default
+        let client = test_client(&server);
+        client.get("https://www.sec.gov/low").await.unwrap();
+        let started = Instant::now();
+        client.get("https://www.sec.gov/low").await.unwrap();
+        assert!(started.elapsed() < PROACTIVE_THROTTLE_DELAY / 2);
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_download_leaves_no_file() {
+        let server = MockServer::start().await;
+        // A gzip header followed by garbage fails partway through decompression
+        let mut corrupt = vec![0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+        corrupt.extend(std::iter::repeat_n(0xAB, 4096));
+        Mock::given(path("/broken.txt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Encoding", "gzip")
+                    .set_body_bytes(corrupt),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/ok.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("complete"))
+            .mount(&server)
+            .await;
+        let client = test_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("filing.txt");
+
+        let result = client
+            .download_streaming("https://www.sec.gov/broken.txt", &target)
+            .await;
+        assert!(result.is_err());
+        assert!(!target.exists());
+        assert!(!partial_path(&target).exists());
+
+        client
+            .download_streaming("https://www.sec.gov/ok.txt", &target)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "complete");
+        assert!(!partial_path(&target).exists());
+
+        // A failed write (missing directory) leaves nothing behind either
+        let missing = dir.path().join("missing").join("filing.txt");
+        assert!(client
+            .download_bytes("https://www.sec.gov/ok.txt", &missing)
+            .await
+            .is_err());
+        assert!(!missing.exists());
+    }
+
+    #[tokio::test]
+    async fn test_get_json_with_meta_returns_etag() {
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000320193.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"5f3c-63a1\"")
+                    .insert_header("Last-Modified", "Fri, 03 Nov 2023 10:04:26 GMT")
+                    .set_body_string(r#"{"cik": "320193"}"#),
+            )
+            .mount(&server)
+            .await;
+        let client = test_client(&server);
+
+        let (json, meta): (serde_json::Value, ResponseMeta) = client
+            .get_json_with_meta("https://data.sec.gov/submissions/CIK0000320193.json")
+            .await
+            .unwrap();
+
+        assert_eq!(json["cik"], "320193");
+        assert_eq!(meta.etag.as_deref(), Some("\"5f3c-63a1\""));
+        assert_eq!(meta.last_modified.as_deref(), Some("Fri, 03 Nov 2023 10:04:26 GMT"));
+        assert_eq!(meta.content_length, Some(17));
+    }
+
+    #[test]
+    fn test_client_contact_email() {
+        let client = Client::new("MyApp", "contact@example.com");
+        assert_eq!(client.contact_email().as_deref(), Some("contact@example.com"));
+
+        let client = Client::new("MyApp", "no email");
+        assert_eq!(client.contact_email(), None);
+    }
+
+    #[test]
+    fn test_from_env_parts() {
+        let vars = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        let client = Client::from_env_parts_with(vars(&[
+            ("SEC_APP_NAME", "Research Tool"),
+            ("SEC_CONTACT_EMAIL", " analyst@example.com "),
+            ("USER_AGENT", "Ignored ignored@example.com"),
+        ]))
+        .unwrap();
+        assert_eq!(client.inner.user_agent(), "Research Tool analyst@example.com");
+
+        let result = Client::from_env_parts_with(vars(&[
+            ("SEC_APP_NAME", "Research Tool"),
+            ("SEC_CONTACT_EMAIL", "nope"),
+        ]));
+        assert!(matches!(result, Err(Error::Custom(msg)) if msg.contains("Invalid contact email")));
+    }
+
+    #[test]
+    fn test_from_env_parts_falls_back_to_user_agent() {
+        let client = Client::from_env_parts_with(|name| match name {
+            "SEC_APP_NAME" => Some("Partial".to_string()),
+            "USER_AGENT" => Some("MyApp contact@example.com".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(client.inner.user_agent(), "MyApp contact@example.com");
+
+        assert!(Client::from_env_parts_with(|_| None).is_err());
     }
 }
@@ -8,15 +8,93 @@ use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tokio::time::sleep;
 
+/// Source of the current time for a [`RateLimiter`].
+///
+/// [`SystemClock`] is used in production; [`MockClock`] lets tests advance
+/// time manually instead of sleeping.
+pub trait Clock: Send + Sync + 'static {
+    /// Current instant.
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by [`Instant::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced clock for deterministic tests.
+///
+/// Clones share the same time, so a test can keep one handle and advance
+/// the clock used by a limiter.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::client::rate_limit::{MockClock, RateLimiter};
+/// use std::time::Duration;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let clock = MockClock::new();
+/// let limiter = RateLimiter::with_clock(1, Duration::from_secs(1), clock.clone());
+///
+/// assert!(limiter.try_acquire().await);
+/// assert!(!limiter.try_acquire().await);
+///
+/// clock.advance(Duration::from_secs(1));
+/// assert!(limiter.try_acquire().await);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<std::sync::Mutex<Instant>>,
+}
+
+impl MockClock {
+    /// Create a clock frozen at the current instant.
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(std::sync::Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Move the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
 /// Token bucket rate limiter.
 ///
 /// Implements a token bucket algorithm to limit the rate of requests.
 /// Tokens are added to the bucket at a fixed rate, and each request
 /// consumes one token.
-pub struct RateLimiter {
+///
+/// Cloning a limiter shares its bucket, so all clones draw from the same tokens.
+#[derive(Clone)]
+pub struct RateLimiter<C: Clock = SystemClock> {
     state: Arc<Mutex<RateLimiterState>>,
     tokens_per_interval: u32,
     interval: Duration,
+    clock: C,
 }
 
 struct RateLimiterState {
@@ -38,17 +116,25 @@ impl RateLimiter {
     /// use sec_o3::client::rate_limit::RateLimiter;
     /// use std::time::Duration;
     ///
-    /// SEC limit: 10 requests per second
+    /// // SEC limit: 10 requests per second
     /// let limiter = RateLimiter::new(10, Duration::from_secs(1));
     /// ```
     pub fn new(tokens_per_interval: u32, interval: Duration) -> Self {
+        Self::with_clock(tokens_per_interval, interval, SystemClock)
+    }
+}
+
+impl<C: Clock> RateLimiter<C> {
+    /// Create a rate limiter that reads time from `clock`.
+    pub fn with_clock(tokens_per_interval: u32, interval: Duration, clock: C) -> Self {
         Self {
             state: Arc::new(Mutex::new(RateLimiterState {
                 tokens: tokens_per_interval as f64,
-                last_update: Instant::now(),
+                last_update: clock.now(),
             })),
             tokens_per_interval,
             interval,
+            clock,
         }
     }
 
@@ -65,22 +151,15 @@ impl RateLimiter {
     /// # async fn example() {
     /// let limiter = RateLimiter::new(10, Duration::from_secs(1));
     ///
-    /// This will wait if no tokens are available
+    /// // This will wait if no tokens are available
     /// limiter.wait().await;
-    /// Now safe to make a request
+    /// // Now safe to make a request
     /// # }
     /// ```
     pub async fn wait(&self) {
         loop {
             let mut state = self.state.lock().await;
-
-            // Add tokens based on time elapsed
-            let now = Instant::now();
-            let elapsed = now.duration_since(state.last_update);
-            let tokens_to_add = elapsed.as_secs_f64() / self.interval.as_secs_f64() * self.tokens_per_interval as f64;
-
-            state.tokens = (state.tokens + tokens_to_add).min(self.tokens_per_interval as f64);
-            state.last_update = now;
+            self.refill(&mut state);
 
             // Try to consume a token
             if state.tokens >= 1.0 {
@@ -106,14 +185,7 @@ impl RateLimiter {
     /// * `false` - If no tokens are available
     pub async fn try_acquire(&self) -> bool {
         let mut state = self.state.lock().await;
-
-        // Add tokens based on time elapsed
-        let now = Instant::now();
-        let elapsed = now.duration_since(state.last_update);
-        let tokens_to_add = elapsed.as_secs_f64() / self.interval.as_secs_f64() * self.tokens_per_interval as f64;
-
-        state.tokens = (state.tokens + tokens_to_add).min(self.tokens_per_interval as f64);
-        state.last_update = now;
+        self.refill(&mut state);
 
         if state.tokens >= 1.0 {
             state.tokens -= 1.0;
@@ -122,6 +194,23 @@ impl RateLimiter {
             false
         }
     }
+
+    /// Number of tokens currently in the bucket, including fractional tokens.
+    pub async fn available_tokens(&self) -> f64 {
+        let mut state = self.state.lock().await;
+        self.refill(&mut state);
+        state.tokens
+    }
+
+    /// Add tokens based on time elapsed since the last update.
+    fn refill(&self, state: &mut RateLimiterState) {
+        let now = self.clock.now();
+        let elapsed = now.duration_since(state.last_update);
+        let tokens_to_add = elapsed.as_secs_f64() / self.interval.as_secs_f64() * self.tokens_per_interval as f64;
+
+        state.tokens = (state.tokens + tokens_to_add).min(self.tokens_per_interval as f64);
+        state.last_update = now;
+    }
 }
 
 #[cfg(test)]
@@ -151,4 +240,29 @@ mod tests {
         assert!(limiter.try_acquire().await); // First succeeds
         assert!(!limiter.try_acquire().await); // Second fails immediately
     }
+
+    #[tokio::test]
+    async fn test_token_math_with_mock_clock() {
+        let clock = MockClock::new();
+        let limiter = RateLimiter::with_clock(10, Duration::from_secs(1), clock.clone());
+
+        // Drain the full bucket
+        for _ in 0..10 {
+            assert!(limiter.try_acquire().await);
+        }
+        assert!(!limiter.try_acquire().await);
+        assert_eq!(limiter.available_tokens().await, 0.0);
+
+        // 250ms at 10 tokens/s refills 2.5 tokens
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(limiter.available_tokens().await, 2.5);
+        assert!(limiter.try_acquire().await);
+        assert!(limiter.try_acquire().await);
+        assert!(!limiter.try_acquire().await);
+        assert_eq!(limiter.available_tokens().await, 0.5);
+
+        // Refill is capped at the bucket size
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(limiter.available_tokens().await, 10.0);
+    }
 }
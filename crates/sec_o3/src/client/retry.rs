@@ -116,10 +116,26 @@ impl RetryPolicy {
     ///     assert_eq!(result, Ok(42));
     /// }
     /// ```
-    pub async fn execute<F, T, E>(&self, mut operation: F) -> Result<T, E>
+    pub async fn execute<F, T, E>(&self, operation: F) -> Result<T, E>
     where
         F: FnMut() -> futures::future::BoxFuture<'static, Result<T, E>>,
         E: std::fmt::Display,
+    {
+        self.execute_if(operation, |_| true).await
+    }
+
+    /// Executes an asynchronous operation, retrying only errors accepted by `should_retry`.
+    ///
+    /// Errors for which `should_retry` returns `false` are returned immediately
+    /// without consuming further attempts.
+    ///
+    /// Backoff sleeps use tokio's clock, so tests can run the schedule
+    /// without real waits under `tokio::time::pause()`.
+    pub async fn execute_if<F, P, T, E>(&self, mut operation: F, should_retry: P) -> Result<T, E>
+    where
+        F: FnMut() -> futures::future::BoxFuture<'static, Result<T, E>>,
+        P: Fn(&E) -> bool,
+        E: std::fmt::Display,
     {
         let mut attempt = 0;
         let mut delay = self.initial_delay;
@@ -129,7 +145,7 @@ impl RetryPolicy {
 
             match operation().await {
                 Ok(result) => return Ok(result),
-                Err(e) if attempt >= self.max_attempts => return Err(e),
+                Err(e) if attempt >= self.max_attempts || !should_retry(&e) => return Err(e),
                 Err(e) => {
                     tracing::warn!(
                         "Attempt {}/{} failed: {}. Retrying in {:?}",
@@ -207,4 +223,44 @@ mod tests {
         assert_eq!(result, Ok(42));
         assert_eq!(call_count, 3);
     }
+
+    #[tokio::test]
+    async fn test_retry_if_stops_on_permanent_error() {
+        let policy = RetryPolicy::new(3);
+        let mut call_count = 0;
+
+        let result = policy
+            .execute_if(
+                || {
+                    call_count += 1;
+                    Box::pin(async move { Err::<i32, String>("permanent error".to_string()) })
+                },
+                |e| !e.starts_with("permanent"),
+            )
+            .await;
+
+        assert_eq!(result, Err("permanent error".to_string()));
+        assert_eq!(call_count, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_backoff_schedule_with_paused_clock() {
+        let policy = RetryPolicy::new(4);
+        let started = tokio::time::Instant::now();
+        let mut attempts = Vec::new();
+
+        let result = policy
+            .execute(|| {
+                attempts.push(started.elapsed());
+                Box::pin(async move { Err::<i32, String>("temporary error".to_string()) })
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            attempts,
+            [0, 100, 300, 700].map(Duration::from_millis),
+            "expected 100ms, 200ms, 400ms between attempts"
+        );
+    }
 }
@@ -25,10 +25,10 @@ use hyper::{HeaderMap, StatusCode};
 /// ```
 /// use sec_o3::client::validation::validate_user_agent;
 ///
-/// Valid User-Agent
+/// // Valid User-Agent
 /// assert!(validate_user_agent("MyApp contact@example.com").is_ok());
 ///
-/// Invalid - missing email
+/// // Invalid - missing email
 /// assert!(validate_user_agent("MyApp").is_err());
 /// ```
 pub fn validate_user_agent(user_agent: &str) -> Result<()> {
@@ -36,8 +36,7 @@ pub fn validate_user_agent(user_agent: &str) -> Result<()> {
         return Err(Error::Custom("User-Agent cannot be empty".to_string()));
     }
 
-    // Check for email-like pattern (simple validation)
-    if !user_agent.contains('@') {
+    if contact_email(user_agent).is_none() {
         return Err(Error::Custom(
             "User-Agent must include contact email per SEC requirements".to_string(),
         ));
@@ -53,6 +52,65 @@ pub fn validate_user_agent(user_agent: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validate a contact email address for the User-Agent.
+///
+/// A light syntactic check: one `@`, a non-empty local part, a domain
+/// containing a dot, and no whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::client::validation::validate_email;
+///
+/// assert!(validate_email("contact@example.com").is_ok());
+/// assert!(validate_email("contact@localhost").is_err());
+/// ```
+pub fn validate_email(email: &str) -> Result<()> {
+    if !is_email(email) {
+        return Err(Error::Custom(format!("Invalid contact email: {:?}", email)));
+    }
+
+    Ok(())
+}
+
+/// Extract the contact email from a User-Agent string.
+///
+/// Returns the first whitespace-separated word that is an email address
+/// (see [`validate_email`]), ignoring surrounding brackets, quotes, and
+/// punctuation such as `<contact@example.com>`.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::client::contact_email;
+///
+/// assert_eq!(contact_email("MyApp <contact@example.com>").as_deref(), Some("contact@example.com"));
+/// assert_eq!(contact_email("MyApp contact at example.com"), None);
+/// ```
+pub fn contact_email(user_agent: &str) -> Option<String> {
+    const WRAPPERS: &[char] = &['<', '>', '(', ')', '[', ']', '"', '\'', ',', ';', ':'];
+
+    user_agent
+        .split_whitespace()
+        .map(|word| word.trim_matches(WRAPPERS).trim_end_matches('.'))
+        .find(|word| is_email(word))
+        .map(str::to_string)
+}
+
+fn is_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
 /// Validate response headers from SEC API.
 ///
 /// Checks for rate limit information and other important headers.
@@ -179,6 +237,27 @@ mod tests {
         assert!(validate_user_agent("a@b.c").is_err()); // Too short
     }
 
+    #[test]
+    fn test_contact_email() {
+        assert_eq!(
+            contact_email("MyApp contact@example.com").as_deref(),
+            Some("contact@example.com")
+        );
+        assert_eq!(
+            contact_email("Company/1.0 (admin@company.com)").as_deref(),
+            Some("admin@company.com")
+        );
+        assert_eq!(
+            contact_email("Research Tool, analyst@example.co.uk.").as_deref(),
+            Some("analyst@example.co.uk")
+        );
+
+        assert_eq!(contact_email("MyApp"), None);
+        assert_eq!(contact_email("MyApp contact@localhost"), None);
+        assert_eq!(contact_email("MyApp @example.com"), None);
+        assert!(validate_user_agent("MyApp contact@localhost").is_err());
+    }
+
     #[test]
     fn test_validate_status() {
         assert!(validate_status(StatusCode::OK).is_ok());
@@ -198,15 +277,13 @@ mod tests {
 
     #[test]
     fn test_response_info_rate_limit_low() {
-        let mut info = ResponseInfo::default();
-
-        info.rate_limit_remaining = Some(5);
-        assert!(!info.is_rate_limit_low());
-
-        info.rate_limit_remaining = Some(1);
-        assert!(info.is_rate_limit_low());
+        let info = |remaining| ResponseInfo {
+            rate_limit_remaining: remaining,
+            ..Default::default()
+        };
 
-        info.rate_limit_remaining = None;
-        assert!(!info.is_rate_limit_low());
+        assert!(!info(Some(5)).is_rate_limit_low());
+        assert!(info(Some(1)).is_rate_limit_low());
+        assert!(!info(None).is_rate_limit_low());
     }
 }
@@ -30,6 +30,10 @@ pub enum Error {
     #[error("XML parsing failed: {0}")]
     XmlError(String),
 
+    /// Failed to parse an HTML document.
+    #[error("HTML parsing failed: {0}")]
+    ParserError(String),
+
     /// File I/O error.
     #[error("File I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -42,6 +46,27 @@ pub enum Error {
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    /// Request rejected with 403 Forbidden.
+    ///
+    /// The SEC answers 403 when the User-Agent is missing or does not
+    /// identify the caller, so the message includes how to fix it.
+    #[error(
+        "Access forbidden (403): {0}. The SEC requires a User-Agent with your application name and \
+         contact email (e.g., \"MyApp contact@example.com\")"
+    )]
+    Forbidden(String),
+
+    /// Server answered with a redirect; holds the `Location` target.
+    ///
+    /// Redirects are not followed automatically, since EDGAR uses them for
+    /// entities whose data moved (e.g., to a successor CIK).
+    #[error("Redirected to {0}")]
+    Redirect(String),
+
+    /// Request did not complete within the configured timeout.
+    #[error("Request timed out after {0:?}")]
+    Timeout(std::time::Duration),
+
     /// Invalid response status code.
     #[error("Invalid response status: {0}")]
     InvalidStatus(hyper::StatusCode),
@@ -51,6 +76,22 @@ pub enum Error {
     Custom(String),
 }
 
+impl Error {
+    /// Whether the failure is transient, so sending the request again may succeed.
+    ///
+    /// Timeouts, connection failures, rate limiting (429), and server errors
+    /// (5xx) are retryable. Answers about the request itself (403, 404,
+    /// redirects, other statuses) and local errors such as invalid input or
+    /// parse failures are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Timeout(_) | Error::HyperError(_) | Error::RateLimitExceeded(_) => true,
+            Error::InvalidStatus(status) => status.is_server_error(),
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +114,19 @@ mod tests {
         let err = Error::Custom("custom error message".to_string());
         assert_eq!(err.to_string(), "custom error message");
     }
+
+    #[test]
+    fn test_is_retryable() {
+        use hyper::StatusCode;
+
+        assert!(Error::Timeout(std::time::Duration::from_secs(30)).is_retryable());
+        assert!(Error::RateLimitExceeded("429".to_string()).is_retryable());
+        assert!(Error::InvalidStatus(StatusCode::BAD_GATEWAY).is_retryable());
+
+        assert!(!Error::InvalidStatus(StatusCode::BAD_REQUEST).is_retryable());
+        assert!(!Error::NotFound("missing".to_string()).is_retryable());
+        assert!(!Error::Forbidden("https://www.sec.gov/".to_string()).is_retryable());
+        assert!(!Error::Redirect("https://www.sec.gov/".to_string()).is_retryable());
+        assert!(!Error::InvalidCik("abc".to_string()).is_retryable());
+    }
 }
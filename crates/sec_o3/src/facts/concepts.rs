@@ -0,0 +1,97 @@
+//! Common XBRL concepts for discovery.
+//!
+//! Most analyses start from the same handful of headline concepts. This
+//! list gives callers a starting point without browsing the taxonomy.
+use super::CompanyFacts;
+
+/// Frequently used concepts as `(taxonomy, tag)` pairs.
+///
+/// Several revenue tags are listed because filers switched from
+/// `Revenues`/`SalesRevenueNet` to the ASC 606 tag in 2018.
+pub const COMMON_CONCEPTS: &[(&str, &str)] = &[
+    // Revenue
+    ("us-gaap", "Revenues"),
+    ("us-gaap", "RevenueFromContractWithCustomerExcludingAssessedTax"),
+    ("us-gaap", "SalesRevenueNet"),
+    // Profitability
+    ("us-gaap", "GrossProfit"),
+    ("us-gaap", "OperatingIncomeLoss"),
+    ("us-gaap", "NetIncomeLoss"),
+    // Per-share data
+    ("us-gaap", "EarningsPerShareBasic"),
+    ("us-gaap", "EarningsPerShareDiluted"),
+    // Balance sheet
+    ("us-gaap", "Assets"),
+    ("us-gaap", "AssetsCurrent"),
+    ("us-gaap", "Liabilities"),
+    ("us-gaap", "LiabilitiesCurrent"),
+    ("us-gaap", "StockholdersEquity"),
+    ("us-gaap", "CashAndCashEquivalentsAtCarryingValue"),
+    ("us-gaap", "LongTermDebt"),
+    // Cash flow
+    ("us-gaap", "NetCashProvidedByUsedInOperatingActivities"),
+    ("us-gaap", "PaymentsToAcquirePropertyPlantAndEquipment"),
+    // Shares
+    ("dei", "EntityCommonStockSharesOutstanding"),
+    ("us-gaap", "WeightedAverageNumberOfDilutedSharesOutstanding"),
+];
+
+/// Common concepts that the company has actually reported.
+///
+/// Returned in [`COMMON_CONCEPTS`] order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::facts::{available_common, get_company_facts};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let facts = get_company_facts(&client, "320193").await?;
+///     for (taxonomy, tag) in available_common(&facts) {
+///         println!("{}:{}", taxonomy, tag);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn available_common(facts: &CompanyFacts) -> Vec<&'static (&'static str, &'static str)> {
+    COMMON_CONCEPTS
+        .iter()
+        .filter(|(taxonomy, tag)| facts.has_concept(taxonomy, tag))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::facts::tests::fixture;
+
+    #[test]
+    fn test_available_common_against_fixture() {
+        let available = available_common(&fixture());
+
+        assert_eq!(
+            available,
+            vec![
+                &("us-gaap", "RevenueFromContractWithCustomerExcludingAssessedTax"),
+                &("us-gaap", "NetIncomeLoss"),
+                &("us-gaap", "EarningsPerShareBasic"),
+                &("us-gaap", "Assets"),
+                &("us-gaap", "Liabilities"),
+                &("us-gaap", "StockholdersEquity"),
+                &("us-gaap", "CashAndCashEquivalentsAtCarryingValue"),
+                &("dei", "EntityCommonStockSharesOutstanding"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_common_concepts_unique() {
+        let mut concepts = COMMON_CONCEPTS.to_vec();
+        concepts.sort_unstable();
+        concepts.dedup();
+        assert_eq!(concepts.len(), COMMON_CONCEPTS.len());
+    }
+}
@@ -0,0 +1,348 @@
+//! Exporting observations through a pluggable sink.
+//!
+//! [`CompanyFacts::export`](super::CompanyFacts::export) walks every
+//! observation once and hands it to a [`FactsSink`], so new output formats
+//! only need to implement the sink.
+use std::io::Write;
+
+use super::{FiscalPeriod, Observation};
+use crate::{Error, Result};
+
+/// Column names shared by the tabular sinks, in output order.
+const COLUMNS: [&str; 11] = [
+    "taxonomy",
+    "tag",
+    "unit",
+    "start",
+    "end",
+    "value",
+    "fy",
+    "fp",
+    "form",
+    "accession",
+    "frame",
+];
+
+/// Destination for flattened XBRL observations.
+pub trait FactsSink {
+    /// Write a single observation.
+    fn write_observation(&mut self, observation: &Observation) -> Result<()>;
+
+    /// Called once after the last observation (e.g., to flush a writer).
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes observations as JSON Lines, one object per line.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::facts::{FactsSink, FiscalPeriod, JsonSink, Observation};
+/// use chrono::NaiveDate;
+///
+/// let observation = Observation {
+///     taxonomy: "us-gaap".to_string(),
+///     tag: "Assets".to_string(),
+///     unit: "USD".to_string(),
+///     start: None,
+///     end: NaiveDate::from_ymd_opt(2023, 9, 30).unwrap(),
+///     value: 352_583_000_000.0,
+///     fy: Some(2023),
+///     fp: Some(FiscalPeriod::FullYear),
+///     form: "10-K".to_string(),
+///     accession: "0000320193-23-000106".to_string(),
+///     frame: Some("CY2023Q3I".to_string()),
+/// };
+///
+/// let mut sink = JsonSink::new(Vec::new());
+/// sink.write_observation(&observation).unwrap();
+/// let output = String::from_utf8(sink.into_inner()).unwrap();
+/// let row: serde_json::Value = serde_json::from_str(output.trim_end()).unwrap();
+/// assert_eq!(row["tag"], "Assets");
+/// assert_eq!(row["end"], "2023-09-30");
+/// ```
+#[derive(Debug)]
+pub struct JsonSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonSink<W> {
+    /// Create a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Consume the sink and return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> FactsSink for JsonSink<W> {
+    fn write_observation(&mut self, observation: &Observation) -> Result<()> {
+        let value = serde_json::json!({
+            "taxonomy": observation.taxonomy,
+            "tag": observation.tag,
+            "unit": observation.unit,
+            "start": observation.start.map(|date| date.to_string()),
+            "end": observation.end.to_string(),
+            "value": observation.value,
+            "fy": observation.fy,
+            "fp": observation.fp.as_ref().map(FiscalPeriod::to_string),
+            "form": observation.form,
+            "accession": observation.accession,
+            "frame": observation.frame,
+        });
+
+        serde_json::to_writer(&mut self.writer, &value).map_err(Error::JsonError)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes observations as CSV with a header row.
+///
+/// Fields containing commas, quotes, or line breaks are quoted, and
+/// missing values are left empty.
+#[derive(Debug)]
+pub struct CsvSink<W: Write> {
+    writer: W,
+    wrote_header: bool,
+}
+
+impl<W: Write> CsvSink<W> {
+    /// Create a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            wrote_header: false,
+        }
+    }
+
+    /// Consume the sink and return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_row<S: AsRef<str>>(&mut self, fields: &[S]) -> Result<()> {
+        let row = fields
+            .iter()
+            .map(|field| csv_escape(field.as_ref()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        writeln!(self.writer, "{}", row)?;
+        Ok(())
+    }
+}
+
+impl<W: Write> FactsSink for CsvSink<W> {
+    fn write_observation(&mut self, observation: &Observation) -> Result<()> {
+        if !self.wrote_header {
+            self.write_row(&COLUMNS)?;
+            self.wrote_header = true;
+        }
+
+        self.write_row(&[
+            observation.taxonomy.clone(),
+            observation.tag.clone(),
+            observation.unit.clone(),
+            observation.start.map(|date| date.to_string()).unwrap_or_default(),
+            observation.end.to_string(),
+            observation.value.to_string(),
+            observation.fy.map(|fy| fy.to_string()).unwrap_or_default(),
+            observation.fp.as_ref().map(FiscalPeriod::to_string).unwrap_or_default(),
+            observation.form.clone(),
+            observation.accession.clone(),
+            observation.frame.clone().unwrap_or_default(),
+        ])
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if !self.wrote_header {
+            self.write_row(&COLUMNS)?;
+            self.wrote_header = true;
+        }
+
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Collects observations into an Arrow [`RecordBatch`](arrow::record_batch::RecordBatch).
+///
+/// Dates are stored as `Date32`; optional fields are nullable columns.
+#[cfg(feature = "arrow")]
+#[derive(Debug, Default)]
+pub struct ArrowSink {
+    taxonomy: arrow::array::StringBuilder,
+    tag: arrow::array::StringBuilder,
+    unit: arrow::array::StringBuilder,
+    start: arrow::array::Date32Builder,
+    end: arrow::array::Date32Builder,
+    value: arrow::array::Float64Builder,
+    fy: arrow::array::Int32Builder,
+    fp: arrow::array::StringBuilder,
+    form: arrow::array::StringBuilder,
+    accession: arrow::array::StringBuilder,
+    frame: arrow::array::StringBuilder,
+}
+
+#[cfg(feature = "arrow")]
+impl ArrowSink {
+    /// Create an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a record batch from the observations written so far.
+    ///
+    /// The sink is left empty and can be reused.
+    pub fn finish_batch(&mut self) -> Result<arrow::record_batch::RecordBatch> {
+        use arrow::array::{ArrayBuilder, ArrayRef};
+        use arrow::datatypes::{Field, Schema};
+        use std::sync::Arc;
+
+        let builders: [&mut dyn ArrayBuilder; COLUMNS.len()] = [
+            &mut self.taxonomy,
+            &mut self.tag,
+            &mut self.unit,
+            &mut self.start,
+            &mut self.end,
+            &mut self.value,
+            &mut self.fy,
+            &mut self.fp,
+            &mut self.form,
+            &mut self.accession,
+            &mut self.frame,
+        ];
+        let columns: Vec<ArrayRef> = builders.into_iter().map(|builder| builder.finish()).collect();
+
+        let nullable = ["start", "fy", "fp", "frame"];
+        let fields: Vec<Field> = COLUMNS
+            .iter()
+            .zip(&columns)
+            .map(|(name, column)| Field::new(*name, column.data_type().clone(), nullable.contains(name)))
+            .collect();
+
+        arrow::record_batch::RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+            .map_err(|e| Error::Custom(format!("Failed to build record batch: {}", e)))
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl FactsSink for ArrowSink {
+    fn write_observation(&mut self, observation: &Observation) -> Result<()> {
+        use arrow::datatypes::Date32Type;
+
+        self.taxonomy.append_value(&observation.taxonomy);
+        self.tag.append_value(&observation.tag);
+        self.unit.append_value(&observation.unit);
+        self.start
+            .append_option(observation.start.map(Date32Type::from_naive_date));
+        self.end.append_value(Date32Type::from_naive_date(observation.end));
+        self.value.append_value(observation.value);
+        self.fy.append_option(observation.fy);
+        self.fp
+            .append_option(observation.fp.as_ref().map(FiscalPeriod::to_string));
+        self.form.append_value(&observation.form);
+        self.accession.append_value(&observation.accession);
+        self.frame.append_option(observation.frame.as_deref());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::facts::tests::fixture;
+
+    /// Sink that only counts what it receives.
+    #[derive(Default)]
+    struct CountingSink {
+        observations: usize,
+        finished: bool,
+    }
+
+    impl FactsSink for CountingSink {
+        fn write_observation(&mut self, _observation: &Observation) -> Result<()> {
+            self.observations += 1;
+            Ok(())
+        }
+
+        fn finish(&mut self) -> Result<()> {
+            self.finished = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_export_counts_observations() {
+        let mut sink = CountingSink::default();
+
+        let written = fixture().export(&mut sink).unwrap();
+
+        assert_eq!(written, 20);
+        assert_eq!(sink.observations, 20);
+        assert!(sink.finished);
+    }
+
+    #[test]
+    fn test_csv_sink() {
+        let facts = fixture();
+        let mut sink = CsvSink::new(Vec::new());
+        facts.export(&mut sink).unwrap();
+        let csv = String::from_utf8(sink.into_inner()).unwrap();
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 21);
+        assert_eq!(lines[0], "taxonomy,tag,unit,start,end,value,fy,fp,form,accession,frame");
+        assert!(lines
+            .iter()
+            .any(|line| line.starts_with("us-gaap,NetIncomeLoss,USD,2022-09-25,")));
+
+        assert_eq!(csv_escape("Apple, Inc."), "\"Apple, Inc.\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_json_sink() {
+        let mut sink = JsonSink::new(Vec::new());
+        fixture().export(&mut sink).unwrap();
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+
+        let rows: Vec<serde_json::Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(rows.len(), 20);
+        let net_income = rows.iter().find(|row| row["tag"] == "NetIncomeLoss").unwrap();
+        assert_eq!(net_income["unit"], "USD");
+        assert_eq!(net_income["fp"], "FY");
+        assert!(rows.iter().any(|row| row["start"].is_null()));
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn test_arrow_sink() {
+        let mut sink = ArrowSink::new();
+        fixture().export(&mut sink).unwrap();
+
+        let batch = sink.finish_batch().unwrap();
+        assert_eq!(batch.num_rows(), 20);
+        assert_eq!(batch.num_columns(), COLUMNS.len());
+        assert!(batch.schema().field_with_name("start").unwrap().is_nullable());
+    }
+}
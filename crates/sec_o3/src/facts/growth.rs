@@ -0,0 +1,104 @@
+//! Growth rates over fact series.
+use chrono::{Days, NaiveDate};
+use std::collections::BTreeMap;
+
+/// Days between a period end and the same period one year earlier.
+const YEAR_DAYS: u64 = 365;
+
+/// Allowed deviation from [`YEAR_DAYS`] when matching the prior-year period.
+///
+/// Covers 52/53-week fiscal years, whose period ends drift by up to a week.
+const MATCH_TOLERANCE_DAYS: u64 = 14;
+
+/// Year-over-year percentage change of a series keyed by period end.
+///
+/// Each value is compared with the value whose period ended closest to one
+/// year earlier (within two weeks). The change is relative to the magnitude
+/// of the prior value, so a loss narrowing from -10 to -5 is +50%. Periods
+/// without a prior-year match, or whose prior value is zero or not finite,
+/// are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sec_o3::facts::yoy_growth;
+/// use std::collections::BTreeMap;
+///
+/// let series = BTreeMap::from([
+///     (NaiveDate::from_ymd_opt(2022, 9, 24).unwrap(), 100.0),
+///     (NaiveDate::from_ymd_opt(2023, 9, 30).unwrap(), 110.0),
+/// ]);
+///
+/// let growth = yoy_growth(&series);
+/// assert_eq!(growth.len(), 1);
+/// assert!((growth[&NaiveDate::from_ymd_opt(2023, 9, 30).unwrap()] - 10.0).abs() < 1e-9);
+/// ```
+pub fn yoy_growth(series: &BTreeMap<NaiveDate, f64>) -> BTreeMap<NaiveDate, f64> {
+    series
+        .iter()
+        .filter_map(|(&end, &value)| {
+            let prior = prior_year_value(series, end)?;
+            if prior == 0.0 || !prior.is_finite() || !value.is_finite() {
+                return None;
+            }
+            Some((end, (value - prior) / prior.abs() * 100.0))
+        })
+        .collect()
+}
+
+/// Value of the period ending closest to one year before `end`.
+fn prior_year_value(series: &BTreeMap<NaiveDate, f64>, end: NaiveDate) -> Option<f64> {
+    let target = end.checked_sub_days(Days::new(YEAR_DAYS))?;
+    let from = target.checked_sub_days(Days::new(MATCH_TOLERANCE_DAYS))?;
+    let to = target.checked_add_days(Days::new(MATCH_TOLERANCE_DAYS))?;
+
+    series
+        .range(from..=to)
+        .min_by_key(|(&date, _)| (date - target).num_days().abs())
+        .map(|(_, &value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_yoy_growth_annual_series() {
+        // Apple net income, fiscal 2021-2023 (52/53-week years)
+        let series = BTreeMap::from([
+            (date(2021, 9, 25), 94_680_000_000.0),
+            (date(2022, 9, 24), 99_803_000_000.0),
+            (date(2023, 9, 30), 96_995_000_000.0),
+        ]);
+
+        let growth = yoy_growth(&series);
+
+        assert_eq!(
+            growth.keys().copied().collect::<Vec<_>>(),
+            vec![date(2022, 9, 24), date(2023, 9, 30)]
+        );
+        assert!((growth[&date(2022, 9, 24)] - 5.4108).abs() < 1e-3);
+        assert!((growth[&date(2023, 9, 30)] + 2.8135).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_yoy_growth_guards_bases() {
+        let series = BTreeMap::from([
+            (date(2021, 12, 31), 0.0),
+            (date(2022, 12, 31), -10.0),
+            (date(2023, 12, 31), -5.0),
+            // No period ends near 2023-06-30
+            (date(2024, 6, 30), 1.0),
+        ]);
+
+        let growth = yoy_growth(&series);
+
+        assert_eq!(growth.len(), 1);
+        assert!((growth[&date(2023, 12, 31)] - 50.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,184 @@
+//! Human-readable labels for common XBRL concepts.
+//!
+//! A small embedded subset of the us-gaap and dei taxonomy labels, so
+//! reports can show "Net Income (Loss)" instead of `us-gaap:NetIncomeLoss`
+//! without loading the full taxonomy files.
+
+/// Standard labels keyed by `taxonomy:Tag`.
+const CONCEPT_LABELS: &[(&str, &str)] = &[
+    // Income statement
+    ("us-gaap:Revenues", "Revenues"),
+    (
+        "us-gaap:RevenueFromContractWithCustomerExcludingAssessedTax",
+        "Revenue from Contract with Customer, Excluding Assessed Tax",
+    ),
+    ("us-gaap:SalesRevenueNet", "Sales Revenue, Net"),
+    ("us-gaap:CostOfRevenue", "Cost of Revenue"),
+    ("us-gaap:CostOfGoodsAndServicesSold", "Cost of Goods and Services Sold"),
+    ("us-gaap:GrossProfit", "Gross Profit"),
+    (
+        "us-gaap:ResearchAndDevelopmentExpense",
+        "Research and Development Expense",
+    ),
+    (
+        "us-gaap:SellingGeneralAndAdministrativeExpense",
+        "Selling, General and Administrative Expense",
+    ),
+    ("us-gaap:OperatingExpenses", "Operating Expenses"),
+    ("us-gaap:OperatingIncomeLoss", "Operating Income (Loss)"),
+    ("us-gaap:InterestExpense", "Interest Expense"),
+    (
+        "us-gaap:IncomeLossFromContinuingOperationsBeforeIncomeTaxesExtraordinaryItemsNoncontrollingInterest",
+        "Income (Loss) from Continuing Operations before Income Taxes",
+    ),
+    ("us-gaap:IncomeTaxExpenseBenefit", "Income Tax Expense (Benefit)"),
+    ("us-gaap:NetIncomeLoss", "Net Income (Loss)"),
+    (
+        "us-gaap:ProfitLoss",
+        "Net Income (Loss), Including Noncontrolling Interest",
+    ),
+    ("us-gaap:EarningsPerShareBasic", "Earnings Per Share, Basic"),
+    ("us-gaap:EarningsPerShareDiluted", "Earnings Per Share, Diluted"),
+    (
+        "us-gaap:WeightedAverageNumberOfSharesOutstandingBasic",
+        "Weighted Average Number of Shares Outstanding, Basic",
+    ),
+    (
+        "us-gaap:WeightedAverageNumberOfDilutedSharesOutstanding",
+        "Weighted Average Number of Shares Outstanding, Diluted",
+    ),
+    // Balance sheet
+    ("us-gaap:Assets", "Assets"),
+    ("us-gaap:AssetsCurrent", "Assets, Current"),
+    (
+        "us-gaap:CashAndCashEquivalentsAtCarryingValue",
+        "Cash and Cash Equivalents",
+    ),
+    (
+        "us-gaap:AccountsReceivableNetCurrent",
+        "Accounts Receivable, Net, Current",
+    ),
+    ("us-gaap:InventoryNet", "Inventory, Net"),
+    (
+        "us-gaap:PropertyPlantAndEquipmentNet",
+        "Property, Plant and Equipment, Net",
+    ),
+    ("us-gaap:Goodwill", "Goodwill"),
+    ("us-gaap:Liabilities", "Liabilities"),
+    ("us-gaap:LiabilitiesCurrent", "Liabilities, Current"),
+    ("us-gaap:AccountsPayableCurrent", "Accounts Payable, Current"),
+    ("us-gaap:LongTermDebt", "Long-Term Debt"),
+    ("us-gaap:LongTermDebtNoncurrent", "Long-Term Debt, Noncurrent"),
+    ("us-gaap:LiabilitiesAndStockholdersEquity", "Liabilities and Equity"),
+    ("us-gaap:StockholdersEquity", "Stockholders' Equity"),
+    (
+        "us-gaap:RetainedEarningsAccumulatedDeficit",
+        "Retained Earnings (Accumulated Deficit)",
+    ),
+    // Cash flow statement
+    (
+        "us-gaap:NetCashProvidedByUsedInOperatingActivities",
+        "Net Cash Provided by (Used in) Operating Activities",
+    ),
+    (
+        "us-gaap:NetCashProvidedByUsedInInvestingActivities",
+        "Net Cash Provided by (Used in) Investing Activities",
+    ),
+    (
+        "us-gaap:NetCashProvidedByUsedInFinancingActivities",
+        "Net Cash Provided by (Used in) Financing Activities",
+    ),
+    (
+        "us-gaap:PaymentsToAcquirePropertyPlantAndEquipment",
+        "Payments to Acquire Property, Plant and Equipment",
+    ),
+    ("us-gaap:PaymentsOfDividends", "Payments of Dividends"),
+    (
+        "us-gaap:PaymentsForRepurchaseOfCommonStock",
+        "Payments for Repurchase of Common Stock",
+    ),
+    (
+        "us-gaap:DepreciationDepletionAndAmortization",
+        "Depreciation, Depletion and Amortization",
+    ),
+    ("us-gaap:ShareBasedCompensation", "Share-Based Compensation"),
+    // Document and entity information
+    (
+        "dei:EntityCommonStockSharesOutstanding",
+        "Entity Common Stock, Shares Outstanding",
+    ),
+    ("dei:EntityPublicFloat", "Entity Public Float"),
+    ("dei:EntityRegistrantName", "Entity Registrant Name"),
+    ("dei:EntityCentralIndexKey", "Entity Central Index Key"),
+    ("dei:DocumentType", "Document Type"),
+    ("dei:DocumentPeriodEndDate", "Document Period End Date"),
+    ("dei:DocumentFiscalYearFocus", "Document Fiscal Year Focus"),
+    ("dei:DocumentFiscalPeriodFocus", "Document Fiscal Period Focus"),
+    ("dei:CurrentFiscalYearEndDate", "Current Fiscal Year End Date"),
+    ("dei:TradingSymbol", "Trading Symbol"),
+];
+
+/// Look up the standard label for a common XBRL concept.
+///
+/// `tag` may be qualified with its taxonomy (`us-gaap:NetIncomeLoss`) or
+/// bare (`NetIncomeLoss`), in which case any taxonomy matches. Returns
+/// `None` for concepts outside the embedded subset.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::facts::concept_label;
+///
+/// assert_eq!(concept_label("us-gaap:NetIncomeLoss"), Some("Net Income (Loss)"));
+/// assert_eq!(concept_label("Assets"), Some("Assets"));
+/// assert_eq!(concept_label("us-gaap:MadeUpConcept"), None);
+/// ```
+pub fn concept_label(tag: &str) -> Option<&'static str> {
+    let tag = tag.trim();
+
+    CONCEPT_LABELS
+        .iter()
+        .find(|(qualified, _)| {
+            if tag.contains(':') {
+                *qualified == tag
+            } else {
+                qualified.split_once(':').is_some_and(|(_, local)| local == tag)
+            }
+        })
+        .map(|(_, label)| *label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concept_label_spot_checks() {
+        assert_eq!(concept_label("us-gaap:NetIncomeLoss"), Some("Net Income (Loss)"));
+        assert_eq!(concept_label("us-gaap:Assets"), Some("Assets"));
+        assert_eq!(
+            concept_label("us-gaap:EarningsPerShareDiluted"),
+            Some("Earnings Per Share, Diluted")
+        );
+        assert_eq!(
+            concept_label("dei:EntityCommonStockSharesOutstanding"),
+            Some("Entity Common Stock, Shares Outstanding")
+        );
+        assert_eq!(concept_label("StockholdersEquity"), Some("Stockholders' Equity"));
+    }
+
+    #[test]
+    fn test_concept_label_unknown_or_wrong_taxonomy() {
+        assert_eq!(concept_label("us-gaap:NotARealConcept"), None);
+        assert_eq!(concept_label("dei:NetIncomeLoss"), None);
+        assert_eq!(concept_label(""), None);
+    }
+
+    #[test]
+    fn test_concept_labels_unique() {
+        let mut tags: Vec<&str> = CONCEPT_LABELS.iter().map(|(tag, _)| *tag).collect();
+        tags.sort_unstable();
+        tags.dedup();
+        assert_eq!(tags.len(), CONCEPT_LABELS.len());
+    }
+}
@@ -0,0 +1,778 @@
+//! XBRL company facts.
+//!
+//! Types for the SEC `companyfacts` API, which returns every XBRL fact a
+//! company has reported, grouped by taxonomy, concept, and unit, plus
+//! helpers for working with the concepts it contains.
+//!
+//! # Submodules
+//!
+//! * [`concepts`] - Common us-gaap/dei concepts for discovery
+//! * [`export`] - Pluggable sinks (JSON, CSV, Arrow) for observations
+//! * [`growth`] - Year-over-year growth of fact series
+//! * [`labels`] - Human-readable labels for common concepts
+//! * [`period`] - Fiscal period codes (FY, Q1–Q4) and fiscal quarter mapping
+pub mod concepts;
+pub mod export;
+pub mod growth;
+pub mod labels;
+pub mod period;
+
+pub use concepts::{available_common, COMMON_CONCEPTS};
+#[cfg(feature = "arrow")]
+pub use export::ArrowSink;
+pub use export::{CsvSink, FactsSink, JsonSink};
+pub use growth::yoy_growth;
+pub use labels::concept_label;
+pub use period::{fiscal_quarter, FiscalPeriod};
+
+use chrono::NaiveDate;
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Client, Error, Result};
+
+/// Duration range, in days, of a fiscal year (52/53-week years included).
+const ANNUAL_DAYS: std::ops::RangeInclusive<i64> = 350..=380;
+
+/// Duration range, in days, of a fiscal quarter (13/14-week quarters included).
+const QUARTERLY_DAYS: std::ops::RangeInclusive<i64> = 84..=98;
+
+/// All XBRL facts reported by a company
+///
+/// Facts are keyed by taxonomy (e.g., "us-gaap", "dei") and then by
+/// concept tag (e.g., "NetIncomeLoss").
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyFacts {
+    /// Company's Central Index Key
+    pub cik: u64,
+    /// Company name as registered with the SEC
+    pub entity_name: String,
+    /// Concepts by taxonomy, then by tag
+    #[serde(default)]
+    pub facts: BTreeMap<String, BTreeMap<String, Concept>>,
+}
+
+impl CompanyFacts {
+    /// Look up a concept by taxonomy and tag (e.g., `("us-gaap", "Assets")`).
+    pub fn concept(&self, taxonomy: &str, tag: &str) -> Option<&Concept> {
+        self.facts.get(taxonomy)?.get(tag)
+    }
+
+    /// Whether the company has reported any values for a concept.
+    pub fn has_concept(&self, taxonomy: &str, tag: &str) -> bool {
+        self.concept(taxonomy, tag)
+            .is_some_and(|concept| concept.units.values().any(|values| !values.is_empty()))
+    }
+
+    /// Flatten all reported values into one list of observations.
+    ///
+    /// Ordered by taxonomy, tag, and unit, then as reported. Values whose
+    /// end date is not a valid YYYY-MM-DD date are skipped.
+    pub fn observations(&self) -> Vec<Observation> {
+        self.iter_observations().collect()
+    }
+
+    /// Flatten all reported values into observations sorted chronologically.
+    ///
+    /// See [`Observation`] for the order.
+    pub fn sorted_observations(&self) -> Vec<Observation> {
+        let mut observations = self.observations();
+        observations.sort();
+        observations
+    }
+
+    /// Values of a concept in one unit reported by annual (`FY`) filings.
+    ///
+    /// For flows (values with a start date), only periods of about a year
+    /// are kept, since annual reports also carry quarterly comparatives.
+    /// Point-in-time values are kept as reported. Values are in reported order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sec_o3::facts::get_company_facts;
+    /// use sec_o3::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> sec_o3::Result<()> {
+    ///     let client = Client::new("MyApp", "contact@example.com");
+    ///     let facts = get_company_facts(&client, "320193").await?;
+    ///
+    ///     for revenue in facts.annual_observations("us-gaap", "Revenues", "USD") {
+    ///         println!("FY{:?}: {}", revenue.fy, revenue.value);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn annual_observations(&self, taxonomy: &str, tag: &str, unit: &str) -> Vec<Observation> {
+        self.unit_observations(taxonomy, tag, unit)
+            .filter(|o| o.fp == Some(FiscalPeriod::FullYear) && o.duration_within(&ANNUAL_DAYS))
+            .collect()
+    }
+
+    /// Values of a concept in one unit reported by quarterly (`Q1`–`Q4`) filings.
+    ///
+    /// For flows, only periods of about three months are kept, which drops
+    /// the year-to-date totals 10-Qs also report. Point-in-time values are
+    /// kept as reported. Values are in reported order.
+    pub fn quarterly_observations(&self, taxonomy: &str, tag: &str, unit: &str) -> Vec<Observation> {
+        self.unit_observations(taxonomy, tag, unit)
+            .filter(|o| {
+                let quarter = o.fp.as_ref().and_then(FiscalPeriod::quarter);
+                quarter.is_some() && o.duration_within(&QUARTERLY_DAYS)
+            })
+            .collect()
+    }
+
+    fn unit_observations<'a>(
+        &'a self,
+        taxonomy: &'a str,
+        tag: &'a str,
+        unit: &'a str,
+    ) -> impl Iterator<Item = Observation> + 'a {
+        self.concept(taxonomy, tag)
+            .and_then(|concept| concept.units.get(unit))
+            .into_iter()
+            .flatten()
+            .filter_map(move |value| Observation::from_value(taxonomy, tag, unit, value))
+    }
+
+    /// Write every observation to `sink`, in [`observations`](Self::observations) order.
+    ///
+    /// Calls [`FactsSink::finish`] after the last observation and returns
+    /// the number of observations written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sec_o3::facts::{get_company_facts, CsvSink};
+    /// use sec_o3::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> sec_o3::Result<()> {
+    ///     let client = Client::new("MyApp", "contact@example.com");
+    ///     let facts = get_company_facts(&client, "320193").await?;
+    ///
+    ///     let file = std::fs::File::create("apple_facts.csv")?;
+    ///     let written = facts.export(&mut CsvSink::new(std::io::BufWriter::new(file)))?;
+    ///     println!("Wrote {} observations", written);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn export(&self, sink: &mut impl FactsSink) -> Result<usize> {
+        let mut written = 0;
+        for observation in self.iter_observations() {
+            sink.write_observation(&observation)?;
+            written += 1;
+        }
+
+        sink.finish()?;
+        Ok(written)
+    }
+
+    fn iter_observations(&self) -> impl Iterator<Item = Observation> + '_ {
+        self.facts.iter().flat_map(|(taxonomy, concepts)| {
+            concepts.iter().flat_map(move |(tag, concept)| {
+                concept.units.iter().flat_map(move |(unit, values)| {
+                    values
+                        .iter()
+                        .filter_map(move |value| Observation::from_value(taxonomy, tag, unit, value))
+                })
+            })
+        })
+    }
+}
+
+/// One reported value with its concept and unit, for flat exports.
+///
+/// Observations sort chronologically: by end date, then by fiscal period
+/// (`FY < Q1 < Q2 < Q3 < Q4`, see [`FiscalPeriod`]), with the remaining
+/// fields as tie-breakers so the order is total. Equality agrees with that
+/// order, so values compare by [`f64::total_cmp`].
+#[derive(Debug, Clone)]
+pub struct Observation {
+    /// Taxonomy of the concept (e.g., "us-gaap")
+    pub taxonomy: String,
+    /// Concept tag (e.g., "NetIncomeLoss")
+    pub tag: String,
+    /// Unit of the value (e.g., "USD")
+    pub unit: String,
+    /// Period start (absent for point-in-time values)
+    pub start: Option<NaiveDate>,
+    /// Period end, or the instant
+    pub end: NaiveDate,
+    /// Reported value
+    pub value: f64,
+    /// Fiscal year of the reporting filing
+    pub fy: Option<i32>,
+    /// Fiscal period of the reporting filing
+    pub fp: Option<FiscalPeriod>,
+    /// Form type of the reporting filing (e.g., "10-K")
+    pub form: String,
+    /// Accession number of the reporting filing
+    pub accession: String,
+    /// Calendar frame the value best represents (e.g., "CY2023Q3I")
+    pub frame: Option<String>,
+}
+
+impl PartialEq for Observation {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Observation {}
+
+impl PartialOrd for Observation {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Observation {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.end
+            .cmp(&other.end)
+            .then_with(|| self.fp.cmp(&other.fp))
+            .then_with(|| self.start.cmp(&other.start))
+            .then_with(|| self.taxonomy.cmp(&other.taxonomy))
+            .then_with(|| self.tag.cmp(&other.tag))
+            .then_with(|| self.unit.cmp(&other.unit))
+            .then_with(|| self.fy.cmp(&other.fy))
+            .then_with(|| self.form.cmp(&other.form))
+            .then_with(|| self.accession.cmp(&other.accession))
+            .then_with(|| self.frame.cmp(&other.frame))
+            .then_with(|| self.value.total_cmp(&other.value))
+    }
+}
+
+impl Observation {
+    /// Flatten a reported value, or `None` if its end date is invalid.
+    fn from_value(taxonomy: &str, tag: &str, unit: &str, value: &FactValue) -> Option<Self> {
+        Some(Observation {
+            taxonomy: taxonomy.to_string(),
+            tag: tag.to_string(),
+            unit: unit.to_string(),
+            start: value.start.as_deref().and_then(parse_date),
+            end: parse_date(&value.end)?,
+            value: value.val,
+            fy: value.fy,
+            fp: value.fp.clone(),
+            form: value.form.clone(),
+            accession: value.accn.clone(),
+            frame: value.frame.clone(),
+        })
+    }
+
+    /// Whether the value is point-in-time or its period length, in days, is within `days`.
+    fn duration_within(&self, days: &std::ops::RangeInclusive<i64>) -> bool {
+        self.start
+            .is_none_or(|start| days.contains(&(self.end - start).num_days()))
+    }
+}
+
+/// A single XBRL concept and its reported values
+#[derive(Debug, Clone, Deserialize)]
+pub struct Concept {
+    /// Taxonomy label (e.g., "Net Income (Loss) Attributable to Parent")
+    pub label: Option<String>,
+    /// Taxonomy definition of the concept
+    pub description: Option<String>,
+    /// Reported values keyed by unit (e.g., "USD", "shares", "USD/shares")
+    #[serde(default)]
+    pub units: BTreeMap<String, Vec<FactValue>>,
+}
+
+/// One reported value of a concept
+#[derive(Debug, Clone, Deserialize)]
+pub struct FactValue {
+    /// Period start in YYYY-MM-DD format (absent for point-in-time values)
+    pub start: Option<String>,
+    /// Period end, or the instant, in YYYY-MM-DD format
+    pub end: String,
+    /// Reported value
+    pub val: f64,
+    /// Accession number of the filing that reported the value
+    pub accn: String,
+    /// Fiscal year of the reporting filing
+    pub fy: Option<i32>,
+    /// Fiscal period of the reporting filing (e.g., FY, Q1)
+    pub fp: Option<FiscalPeriod>,
+    /// Form type of the reporting filing (e.g., "10-K")
+    pub form: String,
+    /// Filing date in YYYY-MM-DD format
+    pub filed: String,
+    /// Calendar frame the value best represents (e.g., "CY2023Q3I")
+    pub frame: Option<String>,
+}
+
+/// Fetch all XBRL facts for a company by CIK
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::facts::get_company_facts;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let facts = get_company_facts(&client, "320193").await?;
+///     println!("{} reports {} taxonomies", facts.entity_name, facts.facts.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn get_company_facts(client: &Client, cik: &str) -> Result<CompanyFacts> {
+    let cik_padded = format!("CIK{:0>10}", cik.trim_start_matches("CIK"));
+    let url = format!("https://data.sec.gov/api/xbrl/companyfacts/{}.json", cik_padded);
+
+    client.get_json(&url).await
+}
+
+/// Fetch XBRL facts for a company, keeping only the requested concepts
+///
+/// `concepts` lists `(taxonomy, tag)` pairs such as `("us-gaap", "Assets")`.
+/// Other concepts are skipped while the response is deserialized, so they
+/// are never allocated; taxonomies with no requested concept are dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::facts::get_company_facts_filtered;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let concepts = [("us-gaap", "Assets"), ("us-gaap", "NetIncomeLoss")];
+///     let facts = get_company_facts_filtered(&client, "320193", &concepts).await?;
+///     assert!(facts.facts["us-gaap"].len() <= 2);
+///     Ok(())
+/// }
+/// ```
+pub async fn get_company_facts_filtered(client: &Client, cik: &str, concepts: &[(&str, &str)]) -> Result<CompanyFacts> {
+    let cik_padded = format!("CIK{:0>10}", cik.trim_start_matches("CIK"));
+    let url = format!("https://data.sec.gov/api/xbrl/companyfacts/{}.json", cik_padded);
+
+    let body = client.get_json_body(&url, client.retry_policy()).await?;
+    parse_company_facts_filtered(&body, concepts)
+}
+
+/// Deserialize a companyfacts response, keeping only `concepts`.
+fn parse_company_facts_filtered(json: &[u8], concepts: &[(&str, &str)]) -> Result<CompanyFacts> {
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let facts = FilteredFacts { concepts }
+        .deserialize(&mut deserializer)
+        .map_err(Error::JsonError)?;
+    deserializer.end().map_err(Error::JsonError)?;
+    Ok(facts)
+}
+
+/// Currency most of a company's monetary values are reported in.
+///
+/// Monetary units are the plain ISO 4217 codes (e.g., "USD", "EUR");
+/// ratios such as "USD/shares" and non-currency units are ignored. Each
+/// reported value counts once, and ties go to the alphabetically first
+/// code. Returns `None` if no value is in a currency.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::facts::{get_company_facts, reporting_currency};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let facts = get_company_facts(&client, "320193").await?;
+///     println!("{:?}", reporting_currency(&facts));
+///     Ok(())
+/// }
+/// ```
+pub fn reporting_currency(facts: &CompanyFacts) -> Option<String> {
+    let is_currency = |unit: &str| unit.len() == 3 && unit.bytes().all(|b| b.is_ascii_uppercase());
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for (unit, values) in facts
+        .facts
+        .values()
+        .flat_map(BTreeMap::values)
+        .flat_map(|concept| &concept.units)
+    {
+        if is_currency(unit) {
+            *counts.entry(unit.as_str()).or_default() += values.len();
+        }
+    }
+
+    // Reversed key order so `max_by` keeps the alphabetically first code on ties
+    counts
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(unit, _)| unit.to_string())
+}
+
+/// Deserializes [`CompanyFacts`], skipping concepts not in the list.
+#[derive(Clone, Copy)]
+struct FilteredFacts<'a> {
+    concepts: &'a [(&'a str, &'a str)],
+}
+
+impl FilteredFacts<'_> {
+    fn wants_taxonomy(&self, taxonomy: &str) -> bool {
+        self.concepts.iter().any(|(t, _)| *t == taxonomy)
+    }
+
+    fn wants_concept(&self, taxonomy: &str, tag: &str) -> bool {
+        self.concepts.iter().any(|(t, c)| *t == taxonomy && *c == tag)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for FilteredFacts<'_> {
+    type Value = CompanyFacts;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> std::result::Result<CompanyFacts, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FilteredFacts<'_> {
+    type Value = CompanyFacts;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a companyfacts object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<CompanyFacts, A::Error> {
+        let (mut cik, mut entity_name, mut facts) = (None, None, BTreeMap::new());
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "cik" => cik = Some(map.next_value()?),
+                "entityName" => entity_name = Some(map.next_value()?),
+                "facts" => facts = map.next_value_seed(FilteredTaxonomies(self))?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(CompanyFacts {
+            cik: cik.ok_or_else(|| de::Error::missing_field("cik"))?,
+            entity_name: entity_name.ok_or_else(|| de::Error::missing_field("entityName"))?,
+            facts,
+        })
+    }
+}
+
+/// The `facts` object: taxonomy -> tag -> concept.
+struct FilteredTaxonomies<'a>(FilteredFacts<'a>);
+
+impl<'de> DeserializeSeed<'de> for FilteredTaxonomies<'_> {
+    type Value = BTreeMap<String, BTreeMap<String, Concept>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FilteredTaxonomies<'_> {
+    type Value = BTreeMap<String, BTreeMap<String, Concept>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of taxonomies")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<Self::Value, A::Error> {
+        let mut taxonomies = BTreeMap::new();
+
+        while let Some(taxonomy) = map.next_key::<String>()? {
+            if !self.0.wants_taxonomy(&taxonomy) {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            }
+
+            let concepts = map.next_value_seed(FilteredConcepts {
+                filter: self.0,
+                taxonomy: &taxonomy,
+            })?;
+            if !concepts.is_empty() {
+                taxonomies.insert(taxonomy, concepts);
+            }
+        }
+
+        Ok(taxonomies)
+    }
+}
+
+/// The concepts of one taxonomy: tag -> concept.
+struct FilteredConcepts<'a, 'b> {
+    filter: FilteredFacts<'a>,
+    taxonomy: &'b str,
+}
+
+impl<'de> DeserializeSeed<'de> for FilteredConcepts<'_, '_> {
+    type Value = BTreeMap<String, Concept>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FilteredConcepts<'_, '_> {
+    type Value = BTreeMap<String, Concept>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of concepts")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<Self::Value, A::Error> {
+        let mut concepts = BTreeMap::new();
+
+        while let Some(tag) = map.next_key::<String>()? {
+            if self.filter.wants_concept(self.taxonomy, &tag) {
+                let concept = map.next_value()?;
+                concepts.insert(tag, concept);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        Ok(concepts)
+    }
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Apple companyfacts excerpt shared by the facts tests.
+    pub(crate) fn fixture() -> CompanyFacts {
+        let json = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/companyfacts.json"));
+        serde_json::from_str(json).expect("fixture should deserialize")
+    }
+
+    #[test]
+    fn test_deserialize_company_facts() {
+        let facts = fixture();
+
+        assert_eq!(facts.cik, 320193);
+        assert_eq!(facts.entity_name, "Apple Inc.");
+
+        let net_income = facts.concept("us-gaap", "NetIncomeLoss").unwrap();
+        let usd = &net_income.units["USD"];
+        assert_eq!(usd.len(), 4);
+        assert_eq!(usd[2].start.as_deref(), Some("2022-09-25"));
+        assert_eq!(usd[2].val, 96_995_000_000.0);
+        assert_eq!(usd[3].fp, Some(FiscalPeriod::Q1));
+
+        let assets = facts.concept("us-gaap", "Assets").unwrap();
+        assert_eq!(assets.units["USD"][0].start, None);
+
+        assert!(facts.has_concept("dei", "EntityCommonStockSharesOutstanding"));
+        assert!(!facts.has_concept("us-gaap", "Goodwill"));
+    }
+
+    #[test]
+    fn test_filtered_facts_keep_only_requested_concepts() {
+        let json = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/companyfacts.json"));
+        let requested = [
+            ("us-gaap", "NetIncomeLoss"),
+            ("us-gaap", "Assets"),
+            ("us-gaap", "Goodwill"),
+            ("ifrs-full", "Revenue"),
+        ];
+
+        let facts = parse_company_facts_filtered(json.as_bytes(), &requested).unwrap();
+
+        assert_eq!(facts.cik, 320193);
+        assert_eq!(facts.entity_name, "Apple Inc.");
+        assert_eq!(facts.facts.keys().collect::<Vec<_>>(), ["us-gaap"]);
+        assert_eq!(
+            facts.facts["us-gaap"].keys().collect::<Vec<_>>(),
+            ["Assets", "NetIncomeLoss"]
+        );
+        assert_eq!(
+            facts.concept("us-gaap", "NetIncomeLoss").unwrap().units["USD"].len(),
+            fixture().concept("us-gaap", "NetIncomeLoss").unwrap().units["USD"].len()
+        );
+
+        assert!(parse_company_facts_filtered(json.as_bytes(), &[])
+            .unwrap()
+            .facts
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_company_facts_filtered() {
+        use crate::test_utils::mock_client;
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let json = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/companyfacts.json"));
+        let server = MockServer::start().await;
+        Mock::given(path("/api/xbrl/companyfacts/CIK0000320193.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(json))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let facts = get_company_facts_filtered(&client, "320193", &[("dei", "EntityCommonStockSharesOutstanding")])
+            .await
+            .unwrap();
+
+        assert_eq!(facts.facts.len(), 1);
+        assert!(facts.has_concept("dei", "EntityCommonStockSharesOutstanding"));
+        assert!(!facts.has_concept("us-gaap", "Assets"));
+    }
+
+    #[test]
+    fn test_observations_flatten_fixture() {
+        let facts = fixture();
+        let observations = facts.observations();
+
+        assert_eq!(observations.len(), 20);
+        assert_eq!(observations.iter().filter(|o| o.taxonomy == "dei").count(), 2);
+        assert_eq!(observations.iter().filter(|o| o.unit == "USD/shares").count(), 2);
+
+        let net_income: Vec<&Observation> = observations.iter().filter(|o| o.tag == "NetIncomeLoss").collect();
+        assert_eq!(net_income.len(), 4);
+        assert_eq!(net_income[2].start, NaiveDate::from_ymd_opt(2022, 9, 25));
+        assert_eq!(net_income[2].value, 96_995_000_000.0);
+        assert_eq!(net_income[3].fp, Some(FiscalPeriod::Q1));
+
+        let assets = observations.iter().find(|o| o.tag == "Assets").unwrap();
+        assert_eq!(assets.start, None);
+    }
+
+    #[test]
+    fn test_sorted_observations() {
+        let observations = fixture().sorted_observations();
+
+        assert_eq!(observations.len(), 20);
+        assert!(observations.windows(2).all(|pair| pair[0].end <= pair[1].end));
+
+        let net_income: Vec<(String, Option<FiscalPeriod>)> = observations
+            .iter()
+            .filter(|o| o.tag == "NetIncomeLoss")
+            .map(|o| (o.end.to_string(), o.fp.clone()))
+            .collect();
+        assert_eq!(
+            net_income,
+            [
+                ("2021-09-25".to_string(), Some(FiscalPeriod::FullYear)),
+                ("2022-09-24".to_string(), Some(FiscalPeriod::FullYear)),
+                ("2023-09-30".to_string(), Some(FiscalPeriod::FullYear)),
+                ("2023-12-30".to_string(), Some(FiscalPeriod::Q1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_observation_order_within_end_date() {
+        let observation = |fp: &str| Observation {
+            taxonomy: "us-gaap".to_string(),
+            tag: "Revenues".to_string(),
+            unit: "USD".to_string(),
+            start: None,
+            end: NaiveDate::from_ymd_opt(2023, 9, 30).unwrap(),
+            value: 1.0,
+            fy: Some(2023),
+            fp: Some(fp.parse().unwrap()),
+            form: "10-K".to_string(),
+            accession: "0000320193-23-000106".to_string(),
+            frame: None,
+        };
+        let mut observations = vec![
+            observation("Q4"),
+            observation("H1"),
+            observation("Q2"),
+            observation("FY"),
+            observation("Q1"),
+            observation("Q3"),
+        ];
+        let mut earlier = observation("Q4");
+        earlier.end = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+        observations.push(earlier);
+
+        observations.sort();
+
+        let order: Vec<String> = observations
+            .iter()
+            .map(|o| o.fp.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(order, ["Q4", "FY", "Q1", "Q2", "Q3", "Q4", "H1"]);
+        assert_eq!(observations[0].end, NaiveDate::from_ymd_opt(2023, 7, 1).unwrap());
+    }
+
+    #[test]
+    fn test_reporting_currency() {
+        let mut facts = fixture();
+        assert_eq!(reporting_currency(&facts).as_deref(), Some("USD"));
+
+        // A few values in a second currency do not change the dominant one
+        let assets = facts.facts.get_mut("us-gaap").unwrap().get_mut("Assets").unwrap();
+        let usd = assets.units["USD"].clone();
+        assets.units.insert("EUR".to_string(), usd[..1].to_vec());
+        assert_eq!(reporting_currency(&facts).as_deref(), Some("USD"));
+
+        facts.facts.get_mut("us-gaap").unwrap().retain(|tag, _| tag == "Assets");
+        facts
+            .facts
+            .get_mut("us-gaap")
+            .unwrap()
+            .get_mut("Assets")
+            .unwrap()
+            .units
+            .remove("USD");
+        assert_eq!(reporting_currency(&facts).as_deref(), Some("EUR"));
+
+        facts.facts.clear();
+        assert_eq!(reporting_currency(&facts), None);
+    }
+
+    #[test]
+    fn test_annual_and_quarterly_observations() {
+        const REVENUE: &str = "RevenueFromContractWithCustomerExcludingAssessedTax";
+        let mut facts = fixture();
+        let revenue = facts.facts.get_mut("us-gaap").unwrap().get_mut(REVENUE).unwrap();
+        let reported = |start: &str, end: &str, val: f64, fp: &str, form: &str| FactValue {
+            start: Some(start.to_string()),
+            end: end.to_string(),
+            val,
+            accn: "0000320193-24-000000".to_string(),
+            fy: Some(2024),
+            fp: Some(fp.to_string().into()),
+            form: form.to_string(),
+            filed: "2024-08-02".to_string(),
+            frame: None,
+        };
+        let usd = revenue.units.get_mut("USD").unwrap();
+        // Nine-month year-to-date total from a Q3 10-Q
+        usd.push(reported("2023-10-01", "2024-06-29", 296_105_000_000.0, "Q3", "10-Q"));
+        // Fourth-quarter comparative in a 10-K
+        usd.push(reported("2023-07-02", "2023-09-30", 89_498_000_000.0, "FY", "10-K"));
+
+        let annual = facts.annual_observations("us-gaap", REVENUE, "USD");
+        assert_eq!(
+            annual.iter().map(|o| o.end.to_string()).collect::<Vec<_>>(),
+            ["2021-09-25", "2022-09-24", "2023-09-30"]
+        );
+
+        let quarterly = facts.quarterly_observations("us-gaap", REVENUE, "USD");
+        assert_eq!(quarterly.len(), 1);
+        assert_eq!(quarterly[0].fp, Some(FiscalPeriod::Q1));
+        assert_eq!(quarterly[0].end, NaiveDate::from_ymd_opt(2023, 12, 30).unwrap());
+
+        assert_eq!(facts.annual_observations("us-gaap", "Assets", "USD").len(), 2);
+        assert!(facts.quarterly_observations("us-gaap", REVENUE, "EUR").is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! Fiscal period codes.
+//!
+//! XBRL facts carry a fiscal period (`fp`) of `FY` for annual reports and
+//! `Q1`–`Q4` for quarters. Other codes (e.g., `H1` from semiannual filers)
+//! are preserved rather than rejected.
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Fiscal period of a reported fact
+///
+/// Periods order as `FY < Q1 < Q2 < Q3 < Q4`, followed by other codes
+/// alphabetically.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(from = "String")]
+pub enum FiscalPeriod {
+    /// Full fiscal year ("FY")
+    FullYear,
+    /// First fiscal quarter ("Q1")
+    Q1,
+    /// Second fiscal quarter ("Q2")
+    Q2,
+    /// Third fiscal quarter ("Q3")
+    Q3,
+    /// Fourth fiscal quarter ("Q4")
+    Q4,
+    /// Any other period code, as reported
+    Other(String),
+}
+
+impl FiscalPeriod {
+    /// Quarter number (1–4), or `None` for full-year and other periods.
+    pub fn quarter(&self) -> Option<u8> {
+        match self {
+            FiscalPeriod::Q1 => Some(1),
+            FiscalPeriod::Q2 => Some(2),
+            FiscalPeriod::Q3 => Some(3),
+            FiscalPeriod::Q4 => Some(4),
+            FiscalPeriod::FullYear | FiscalPeriod::Other(_) => None,
+        }
+    }
+}
+
+impl FromStr for FiscalPeriod {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let period = match s.trim().to_ascii_uppercase().as_str() {
+            "FY" => FiscalPeriod::FullYear,
+            "Q1" => FiscalPeriod::Q1,
+            "Q2" => FiscalPeriod::Q2,
+            "Q3" => FiscalPeriod::Q3,
+            "Q4" => FiscalPeriod::Q4,
+            _ => FiscalPeriod::Other(s.trim().to_string()),
+        };
+        Ok(period)
+    }
+}
+
+impl From<String> for FiscalPeriod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(period) => period,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl fmt::Display for FiscalPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            FiscalPeriod::FullYear => "FY",
+            FiscalPeriod::Q1 => "Q1",
+            FiscalPeriod::Q2 => "Q2",
+            FiscalPeriod::Q3 => "Q3",
+            FiscalPeriod::Q4 => "Q4",
+            FiscalPeriod::Other(code) => code,
+        };
+        f.write_str(code)
+    }
+}
+
+/// Fiscal year and quarter of a period ending on `end`.
+///
+/// `fiscal_year_end_month` is the calendar month (1–12) in which the
+/// company's fiscal year ends; the fiscal year is named for the calendar
+/// year it ends in. Companies on a 52/53-week calendar often close a period
+/// in the first days of the following month (e.g., Oct 1 for a September
+/// year end), so period ends on day 1–7 count toward the previous month.
+/// Returns `None` if `fiscal_year_end_month` is not in `1..=12`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sec_o3::facts::fiscal_quarter;
+///
+/// // Apple's fiscal year ends in September
+/// let end = NaiveDate::from_ymd_opt(2023, 12, 30).unwrap();
+/// assert_eq!(fiscal_quarter(end, 9), Some((2024, 1)));
+/// ```
+pub fn fiscal_quarter(end: NaiveDate, fiscal_year_end_month: u32) -> Option<(i32, u8)> {
+    if !(1..=12).contains(&fiscal_year_end_month) {
+        return None;
+    }
+
+    let (mut year, mut month) = (end.year(), end.month());
+    if end.day() <= 7 {
+        if month == 1 {
+            year -= 1;
+            month = 12;
+        } else {
+            month -= 1;
+        }
+    }
+
+    let fiscal_year = if month > fiscal_year_end_month { year + 1 } else { year };
+    // Months elapsed since the fiscal year began, 0..=11
+    let offset = (month + 11 - fiscal_year_end_month) % 12;
+
+    Some((fiscal_year, (offset / 3 + 1) as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiscal_period_round_trip() {
+        for code in ["FY", "Q1", "Q2", "Q3", "Q4", "H1"] {
+            let period: FiscalPeriod = code.parse().unwrap();
+            assert_eq!(period.to_string(), code);
+        }
+
+        assert_eq!("fy".parse::<FiscalPeriod>().unwrap(), FiscalPeriod::FullYear);
+        assert_eq!(
+            "H1".parse::<FiscalPeriod>().unwrap(),
+            FiscalPeriod::Other("H1".to_string())
+        );
+        assert_eq!(FiscalPeriod::Q3.quarter(), Some(3));
+        assert_eq!(FiscalPeriod::FullYear.quarter(), None);
+    }
+
+    #[test]
+    fn test_fiscal_period_deserialize_tolerant() {
+        let periods: Vec<FiscalPeriod> = serde_json::from_str(r#"["FY", "Q2", "CY"]"#).unwrap();
+        assert_eq!(
+            periods,
+            vec![
+                FiscalPeriod::FullYear,
+                FiscalPeriod::Q2,
+                FiscalPeriod::Other("CY".to_string())
+            ]
+        );
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_fiscal_quarter_september_year_end() {
+        // Apple fiscal 2023 and 2024 quarter ends
+        assert_eq!(fiscal_quarter(date(2022, 12, 31), 9), Some((2023, 1)));
+        assert_eq!(fiscal_quarter(date(2023, 4, 1), 9), Some((2023, 2)));
+        assert_eq!(fiscal_quarter(date(2023, 7, 1), 9), Some((2023, 3)));
+        assert_eq!(fiscal_quarter(date(2023, 9, 30), 9), Some((2023, 4)));
+        assert_eq!(fiscal_quarter(date(2023, 12, 30), 9), Some((2024, 1)));
+        // A 53-week year ending just after September
+        assert_eq!(fiscal_quarter(date(2022, 10, 1), 9), Some((2022, 4)));
+    }
+
+    #[test]
+    fn test_fiscal_quarter_calendar_year() {
+        assert_eq!(fiscal_quarter(date(2023, 3, 31), 12), Some((2023, 1)));
+        assert_eq!(fiscal_quarter(date(2023, 12, 31), 12), Some((2023, 4)));
+        assert_eq!(fiscal_quarter(date(2024, 1, 2), 12), Some((2023, 4)));
+    }
+
+    #[test]
+    fn test_fiscal_quarter_invalid_month() {
+        assert_eq!(fiscal_quarter(date(2023, 3, 31), 0), None);
+        assert_eq!(fiscal_quarter(date(2023, 3, 31), 13), None);
+    }
+}
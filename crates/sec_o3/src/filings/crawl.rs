@@ -0,0 +1,116 @@
+//! Concurrent submissions crawls over many companies.
+//!
+//! Fetches submissions for a list of CIKs with bounded concurrency. All
+//! requests go through one [`Client`], so they share its rate limiter, and
+//! a failure for one CIK is reported alongside its CIK without stopping
+//! the crawl.
+use futures::stream::{self, Stream, StreamExt};
+
+use super::{get_submissions, Submissions};
+use crate::{Client, Result};
+
+/// Fetch submissions for each CIK, yielding results as they complete.
+///
+/// At most `concurrency` requests are in flight at once (at least one),
+/// and the client's rate limit applies across all of them. Each item pairs
+/// the CIK as given with its result, so errors are isolated per company.
+/// Results arrive in completion order, not input order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use sec_o3::Client;
+/// use sec_o3::filings::crawl_submissions;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let ciks = vec!["320193".to_string(), "789019".to_string()];
+///
+///     let mut results = crawl_submissions(&client, ciks, 4);
+///     while let Some((cik, result)) = results.next().await {
+///         match result {
+///             Ok(submissions) => println!("{}: {}", cik, submissions.name),
+///             Err(e) => eprintln!("{}: {}", cik, e),
+///         }
+///     }
+/// }
+/// ```
+pub fn crawl_submissions(
+    client: &Client,
+    ciks: Vec<String>,
+    concurrency: usize,
+) -> impl Stream<Item = (String, Result<Submissions>)> {
+    let client = client.clone();
+
+    stream::iter(ciks)
+        .map(move |cik| {
+            let client = client.clone();
+            async move {
+                let result = get_submissions(&client, &cik).await;
+                (cik, result)
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use crate::Error;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn submissions(cik: &str, name: &str) -> serde_json::Value {
+        json!({
+            "cik": cik,
+            "entityType": "operating",
+            "sic": "3571",
+            "sicDescription": "Electronic Computers",
+            "name": name,
+            "tickers": [],
+            "exchanges": [],
+            "filings": {
+                "recent": {
+                    "accessionNumber": [],
+                    "form": [],
+                    "primaryDocument": [],
+                    "acceptanceDateTime": []
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_crawl_submissions_isolates_errors() {
+        let server = MockServer::start().await;
+        for (cik, name, delay) in [("320193", "Apple Inc.", 50), ("789019", "Microsoft Corp", 0)] {
+            Mock::given(path(format!("/submissions/CIK{:0>10}.json", cik)))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(submissions(cik, name))
+                        .set_delay(Duration::from_millis(delay)),
+                )
+                .mount(&server)
+                .await;
+        }
+        Mock::given(path("/submissions/CIK0000000404.json"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let ciks = vec!["320193".to_string(), "404".to_string(), "789019".to_string()];
+        let results: HashMap<String, Result<Submissions>> = crawl_submissions(&client, ciks, 2).collect().await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results["320193"].as_ref().unwrap().name, "Apple Inc.");
+        assert_eq!(results["789019"].as_ref().unwrap().name, "Microsoft Corp");
+        assert!(matches!(results["404"], Err(Error::NotFound(_))));
+    }
+}
@@ -0,0 +1,394 @@
+//! EDGAR current events feed.
+//!
+//! `browse-edgar?action=getcurrent` lists filings accepted in the current
+//! business day, newest first, as an Atom feed. Polling it is the usual way
+//! to get same-day notice of new filings.
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use regex::Regex;
+
+use crate::parse::resolve_xml_entity;
+use crate::utils::{build_current_events_page_url, build_current_events_url};
+use crate::{Client, Error, Result};
+
+/// Most entries EDGAR serves per page of the current events feed.
+const FEED_PAGE_SIZE: u32 = 100;
+
+/// Most feed pages [`latest_filings_by_form`] requests in one call.
+const MAX_FEED_PAGES: u32 = 20;
+
+/// Entry title, e.g. "8-K - Apple Inc. (0000320193) (Filer)".
+static ENTRY_TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.+?) - (.+) \((\d{10})\) \(([^)]*)\)$").unwrap());
+
+/// One filing in the current events feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedEntry {
+    /// Form type (e.g., "8-K")
+    pub form_type: String,
+    /// Name of the filer or subject company
+    pub company_name: String,
+    /// Company's 10-digit Central Index Key
+    pub cik: String,
+    /// Role of the company in the filing (e.g., "Filer", "Subject", "Reporting")
+    pub role: Option<String>,
+    /// Accession number (e.g., "0000320193-24-000001")
+    pub accession_number: String,
+    /// URL of the filing index page
+    pub link: String,
+    /// Time the filing was accepted
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// Fetch the most recent filings from the current events feed.
+///
+/// # Arguments
+///
+/// * `client` - SEC client
+/// * `form_type` - Only filings of this form type (e.g., "8-K"); `None` for all
+/// * `count` - Maximum number of entries (EDGAR serves at most 100 per page)
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::current_filings;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     for entry in current_filings(&client, Some("8-K"), 40).await? {
+///         println!("{} {} {}", entry.form_type, entry.company_name, entry.accession_number);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn current_filings(client: &Client, form_type: Option<&str>, count: u32) -> Result<Vec<FeedEntry>> {
+    let url = build_current_events_url(form_type, count);
+    let body = client.get_bytes(&url).await?;
+
+    let mut entries = parse_current_feed(&body)?;
+    entries.truncate(count as usize);
+    Ok(entries)
+}
+
+/// Fetch up to `limit` of today's filings of exactly one form type, newest first.
+///
+/// EDGAR's form filter matches by prefix, so asking for "8-K" also lists
+/// "8-K/A" and "8-K12B"; those are dropped here. Pages are requested until
+/// `limit` filings are found or the feed runs out, which makes this
+/// suited to "the latest 100 8-Ks" across all companies. Paging also stops
+/// after 20 pages, or at a page that lists no filing not already seen, so
+/// fewer than `limit` filings may be returned. A filing listed once per
+/// company (e.g., filer and subject) is returned once, as its first entry.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::latest_filings_by_form;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     for entry in latest_filings_by_form(&client, "8-K", 100).await? {
+///         println!("{} {}", entry.company_name, entry.link);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn latest_filings_by_form(client: &Client, form_type: &str, limit: usize) -> Result<Vec<FeedEntry>> {
+    let form_type = form_type.trim();
+    let mut entries = Vec::new();
+    let mut seen = HashSet::new();
+
+    for page_number in 0..MAX_FEED_PAGES {
+        if entries.len() >= limit {
+            break;
+        }
+
+        let url = build_current_events_page_url(Some(form_type), page_number * FEED_PAGE_SIZE, FEED_PAGE_SIZE);
+        let page = parse_current_feed(&client.get_bytes(&url).await?)?;
+        let page_len = page.len();
+
+        let mut added = false;
+        for entry in page {
+            if !seen.insert(entry.accession_number.clone()) {
+                continue;
+            }
+            added = true;
+            if entry.form_type.eq_ignore_ascii_case(form_type) {
+                entries.push(entry);
+            }
+        }
+
+        // A short page is the end of the feed; a page of repeats means
+        // EDGAR is serving the same entries again
+        if page_len < FEED_PAGE_SIZE as usize || !added {
+            break;
+        }
+    }
+
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+/// Parse a current events Atom feed.
+///
+/// The feed's declared encoding (usually ISO-8859-1) is honored. Entries
+/// without an accession number are skipped.
+///
+/// # Errors
+///
+/// Returns `Error::XmlError` if the feed is not well-formed XML.
+pub fn parse_current_feed(xml: &[u8]) -> Result<Vec<FeedEntry>> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buf = Vec::new();
+
+    let mut entries = Vec::new();
+    let mut entry: Option<EntryFields> = None;
+    let mut element = String::new();
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(start)) => {
+                let name = local_name(&start);
+                if name == "entry" {
+                    entry = Some(EntryFields::default());
+                } else if let Some(entry) = entry.as_mut() {
+                    entry.read_attributes(&name, &start, &reader)?;
+                }
+                element = name;
+                text.clear();
+            }
+            Ok(Event::Empty(start)) => {
+                if let Some(entry) = entry.as_mut() {
+                    entry.read_attributes(&local_name(&start), &start, &reader)?;
+                }
+            }
+            Ok(Event::Text(t)) => text.push_str(&t.decode().map_err(|e| Error::XmlError(e.to_string()))?),
+            Ok(Event::GeneralRef(entity)) => text.push_str(&resolve_xml_entity(&entity)?),
+            Ok(Event::End(end)) => {
+                let name = String::from_utf8_lossy(end.local_name().as_ref()).into_owned();
+                if name == "entry" {
+                    entries.extend(entry.take().and_then(EntryFields::into_entry));
+                } else if let Some(entry) = entry.as_mut() {
+                    let value = text.trim().to_string();
+                    match element.as_str() {
+                        "title" => entry.title = value,
+                        "id" => entry.id = value,
+                        "updated" => entry.updated = value,
+                        _ => {}
+                    }
+                }
+                text.clear();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlError(e.to_string())),
+        }
+        buf.clear();
+    }
+
+    Ok(entries)
+}
+
+/// Raw fields of an `<entry>` collected while reading.
+#[derive(Default)]
+struct EntryFields {
+    title: String,
+    id: String,
+    updated: String,
+    link: String,
+    term: Option<String>,
+}
+
+impl EntryFields {
+    /// Capture the `href` of `<link>` and the `term` of `<category>`.
+    fn read_attributes(&mut self, name: &str, start: &BytesStart, reader: &Reader<&[u8]>) -> Result<()> {
+        let wanted: &[u8] = match name {
+            "link" => b"href",
+            "category" => b"term",
+            _ => return Ok(()),
+        };
+
+        for attr in start.attributes() {
+            let attr = attr.map_err(|e| Error::XmlError(e.to_string()))?;
+            if attr.key.local_name().as_ref() != wanted {
+                continue;
+            }
+            let value = attr
+                .decode_and_unescape_value(reader.decoder())
+                .map_err(|e| Error::XmlError(e.to_string()))?
+                .into_owned();
+            match name {
+                "link" => self.link = value,
+                _ => self.term = Some(value),
+            }
+        }
+        Ok(())
+    }
+
+    fn into_entry(self) -> Option<FeedEntry> {
+        let accession_number = self
+            .id
+            .rsplit_once("accession-number=")
+            .map(|(_, accession)| accession.to_string())?;
+
+        let title = ENTRY_TITLE.captures(&self.title);
+        let field = |i: usize| title.as_ref().map(|caps| caps[i].to_string());
+
+        Some(FeedEntry {
+            form_type: self.term.or_else(|| field(1)).unwrap_or_default(),
+            company_name: field(2).unwrap_or(self.title.clone()),
+            cik: field(3).unwrap_or_default(),
+            role: field(4).filter(|role| !role.is_empty()),
+            accession_number,
+            link: self.link,
+            updated: DateTime::parse_from_rfc3339(&self.updated)
+                .ok()
+                .map(|updated| updated.with_timezone(&Utc)),
+        })
+    }
+}
+
+pub(super) fn local_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.local_name().as_ref()).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use wiremock::matchers::{path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const FEED: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/current_events.atom"));
+
+    #[test]
+    fn test_parse_captured_current_events_feed() {
+        let entries = parse_current_feed(FEED.as_bytes()).unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            FeedEntry {
+                form_type: "8-K".to_string(),
+                company_name: "Tesla, Inc.".to_string(),
+                cik: "0001318605".to_string(),
+                role: Some("Filer".to_string()),
+                accession_number: "0000950170-24-000331".to_string(),
+                link:
+                    "https://www.sec.gov/Archives/edgar/data/1318605/000095017024000331/0000950170-24-000331-index.htm"
+                        .to_string(),
+                updated: "2024-01-02T21:45:11Z".parse().ok(),
+            }
+        );
+        assert_eq!(entries[1].company_name, "AT&T INC.");
+        assert_eq!(entries[2].form_type, "8-K/A");
+        assert_eq!(entries[2].role.as_deref(), Some("Subject"));
+    }
+
+    #[tokio::test]
+    async fn test_current_filings_fetches_feed() {
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .and(query_param("action", "getcurrent"))
+            .and(query_param("type", "8-K"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FEED))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let entries = current_filings(&client, Some("8-K"), 2).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].accession_number, "0000732717-24-000002");
+    }
+
+    #[tokio::test]
+    async fn test_latest_filings_by_form_drops_other_forms() {
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .and(query_param("action", "getcurrent"))
+            .and(query_param("type", "8-K"))
+            .and(query_param("count", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(FEED))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let entries = latest_filings_by_form(&client, "8-K", 10).await.unwrap();
+        let accessions: Vec<&str> = entries.iter().map(|e| e.accession_number.as_str()).collect();
+        assert_eq!(accessions, ["0000950170-24-000331", "0000732717-24-000002"]);
+        assert!(entries.iter().all(|entry| entry.form_type == "8-K"));
+    }
+
+    #[tokio::test]
+    async fn test_latest_filings_by_form_pages_until_limit() {
+        let feed = |first: u32, count: u32| {
+            let entries: String = (first..first + count)
+                .map(|n| {
+                    format!(
+                        "<entry><title>4 - Insider {n} (00000{n:05}) (Reporting)</title>\
+                         <link rel=\"alternate\" type=\"text/html\" href=\"https://www.sec.gov/Archives/x-index.htm\"/>\
+                         <updated>2024-01-02T17:00:00-05:00</updated>\
+                         <id>urn:tag:sec.gov,2008:accession-number=0001209191-24-{n:06}</id></entry>"
+                    )
+                })
+                .collect();
+            format!("<feed xmlns=\"http://www.w3.org/2005/Atom\">{}</feed>", entries)
+        };
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .and(query_param("start", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(feed(100, 100)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(feed(0, 100)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let entries = latest_filings_by_form(&client, "4", 150).await.unwrap();
+        assert_eq!(entries.len(), 150);
+        assert_eq!(entries[149].accession_number, "0001209191-24-000149");
+    }
+
+    #[tokio::test]
+    async fn test_latest_filings_by_form_stops_on_repeated_page() {
+        let entries: String = (0..100)
+            .map(|n| {
+                format!(
+                    "<entry><title>4 - Insider {n} (00000{n:05}) (Reporting)</title>\
+                     <link rel=\"alternate\" type=\"text/html\" href=\"https://www.sec.gov/Archives/x-index.htm\"/>\
+                     <updated>2024-01-02T17:00:00-05:00</updated>\
+                     <id>urn:tag:sec.gov,2008:accession-number=0001209191-24-{n:06}</id></entry>"
+                )
+            })
+            .collect();
+        let feed = format!("<feed xmlns=\"http://www.w3.org/2005/Atom\">{}</feed>", entries);
+        let server = MockServer::start().await;
+        // Every page repeats the first one
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(feed))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let entries = latest_filings_by_form(&client, "4", 1000).await.unwrap();
+        assert_eq!(entries.len(), 100);
+    }
+}
@@ -0,0 +1,136 @@
+//! Form type classification.
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Periodic report forms that include financial statements (and, since
+/// the XBRL phase-in, XBRL exhibits).
+const FINANCIAL_REPORT_FORMS: &[&str] = &[
+    "10-K", "10-K405", "10-KT", "10-KSB", "10-Q", "10-QT", "10-QSB", "20-F", "40-F",
+];
+
+/// An EDGAR form type (e.g., "10-K", "8-K/A", "DEF 14A").
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::filings::FormType;
+///
+/// let form = FormType::new("10-K/A");
+/// assert!(form.is_amendment());
+/// assert_eq!(form.base(), "10-K");
+/// assert!(form.has_financial_statements());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormType(String);
+
+impl FormType {
+    /// Wrap a form type as reported by EDGAR, trimmed and uppercased.
+    pub fn new(form_type: &str) -> Self {
+        Self(form_type.trim().to_ascii_uppercase())
+    }
+
+    /// The form type as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the form amends an earlier filing (ends in "/A").
+    pub fn is_amendment(&self) -> bool {
+        self.0.ends_with("/A")
+    }
+
+    /// The form type without the amendment suffix.
+    pub fn base(&self) -> &str {
+        self.0.strip_suffix("/A").unwrap_or(&self.0)
+    }
+
+    /// Whether the form is a periodic report with financial statements.
+    ///
+    /// True for annual and quarterly reports (10-K, 10-Q, 20-F, 40-F, their
+    /// transition and legacy variants, and amendments), which are the forms
+    /// that carry XBRL financial data. False for current reports, proxies,
+    /// ownership forms, and registration statements.
+    pub fn has_financial_statements(&self) -> bool {
+        FINANCIAL_REPORT_FORMS.contains(&self.base())
+    }
+}
+
+impl From<&str> for FormType {
+    fn from(form_type: &str) -> Self {
+        Self::new(form_type)
+    }
+}
+
+impl FromStr for FormType {
+    type Err = Infallible;
+
+    fn from_str(form_type: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(form_type))
+    }
+}
+
+impl fmt::Display for FormType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Whether `candidate` is an amendment of a filing of form `original`.
+///
+/// Both forms are normalized; the candidate must end in "/A" and share the
+/// original's base form. An amendment also amends earlier amendments of the
+/// same form.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::filings::amends;
+///
+/// assert!(amends("10-K/A", "10-K"));
+/// assert!(!amends("10-Q/A", "10-K"));
+/// assert!(!amends("10-K", "10-K"));
+/// ```
+pub fn amends(candidate: &str, original: &str) -> bool {
+    let Ok(candidate) = candidate.parse::<FormType>();
+    let Ok(original) = original.parse::<FormType>();
+    candidate.is_amendment() && candidate.base() == original.base()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_financial_statements() {
+        for form in ["10-K", "10-K/A", "10-Q", "10-q/a", "20-F", "40-F/A", "10-KT"] {
+            assert!(FormType::new(form).has_financial_statements(), "{}", form);
+        }
+        for form in ["8-K", "8-K/A", "DEF 14A", "4", "SC 13G", "S-1", "10-K12B"] {
+            assert!(!FormType::new(form).has_financial_statements(), "{}", form);
+        }
+    }
+
+    #[test]
+    fn test_amendment_and_base() {
+        let form = FormType::from(" 8-k/a ");
+        assert_eq!(form.as_str(), "8-K/A");
+        assert!(form.is_amendment());
+        assert_eq!(form.base(), "8-K");
+        assert!(!FormType::new("DEF 14A").is_amendment());
+        assert_eq!("10-k ".parse::<FormType>().unwrap(), FormType::new("10-K"));
+    }
+
+    #[test]
+    fn test_amends() {
+        assert!(amends("10-K/A", "10-K"));
+        assert!(amends("8-k/a", " 8-K "));
+        assert!(amends("10-K/A", "10-K/A"));
+
+        assert!(!amends("10-K", "10-K"));
+        assert!(!amends("10-K", "10-K/A"));
+        assert!(!amends("10-Q/A", "10-K"));
+        assert!(!amends("8-K/A", "10-K"));
+        assert!(!amends("DEF 14A", "DEF 14"));
+    }
+}
@@ -0,0 +1,452 @@
+//! Older filings from submission archive files.
+//!
+//! The submissions API returns only recent filings inline; older ones are
+//! split into archive files listed in `filings.files`. This module fetches
+//! those archives and merges them with the recent filings into one stream.
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+
+use super::{get_submissions, ArchiveFile, Filing, RecentFilings};
+use crate::{Client, Result};
+
+/// Fetch one submissions archive file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::{get_filings_archive, get_submissions};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let submissions = get_submissions(&client, "320193").await?;
+///     for file in &submissions.filings.files {
+///         let archive = get_filings_archive(&client, file).await?;
+///         println!("{}: {} filings", file.name, archive.accession_number.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn get_filings_archive(client: &Client, file: &ArchiveFile) -> Result<RecentFilings> {
+    let url = format!("https://data.sec.gov/submissions/{}", file.name);
+    let archive: RecentFilings = client.get_json(&url).await?;
+    archive.validate_lengths()?;
+
+    Ok(archive)
+}
+
+/// Stream all filings for a company: recent filings, then each archive.
+///
+/// Archives are fetched only when the stream reaches them, so consumers
+/// that stop early avoid the extra requests. Filings appear in SEC order
+/// within each source. The stream ends after the first error.
+pub fn merged_filings_stream(client: &Client, cik: &str) -> impl Stream<Item = Result<Filing>> {
+    MergeState::new(client, cik, false).into_stream()
+}
+
+/// Stream all filings for a company, newest first across all sources.
+///
+/// Performs a k-way merge over the recent filings and the archives by
+/// acceptance date. An archive is fetched only once the merge reaches its
+/// `filing_to` date, so older archives are still loaded on demand.
+pub fn merged_filings_stream_sorted(client: &Client, cik: &str) -> impl Stream<Item = Result<Filing>> {
+    MergeState::new(client, cik, true).into_stream()
+}
+
+/// Limits on how much filing history a merged stream retrieves.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sec_o3::filings::HistoryLimit;
+///
+/// // The newest two archives, and nothing filed before 2020
+/// let limit = HistoryLimit {
+///     max_archives: Some(2),
+///     since: NaiveDate::from_ymd_opt(2020, 1, 1),
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryLimit {
+    /// Maximum number of archive files to fetch, newest first
+    pub max_archives: Option<usize>,
+    /// Earliest filing date to include; older archives are never fetched
+    pub since: Option<NaiveDate>,
+}
+
+impl HistoryLimit {
+    fn admits(&self, filing: &Filing) -> bool {
+        self.since
+            .is_none_or(|since| filing.acceptance_date.date_naive() >= since)
+    }
+}
+
+/// Stream filings like [`merged_filings_stream`], retrieving only limited history.
+///
+/// Archives are considered newest first. Those ending before `limit.since`
+/// and those beyond `limit.max_archives` are never downloaded, and filings
+/// accepted before `limit.since` are skipped.
+pub fn merged_filings_stream_limited(
+    client: &Client,
+    cik: &str,
+    limit: HistoryLimit,
+) -> impl Stream<Item = Result<Filing>> {
+    let mut state = MergeState::new(client, cik, false);
+    state.limit = limit;
+    state.into_stream()
+}
+
+/// State shared by the merged filing streams.
+struct MergeState {
+    client: Client,
+    cik: String,
+    sorted: bool,
+    limit: HistoryLimit,
+    started: bool,
+    done: bool,
+    /// Filings loaded so far, one queue per source
+    sources: Vec<VecDeque<Filing>>,
+    /// Archives not yet fetched
+    pending: VecDeque<ArchiveFile>,
+}
+
+impl MergeState {
+    fn new(client: &Client, cik: &str, sorted: bool) -> Self {
+        Self {
+            client: client.clone(),
+            cik: cik.to_string(),
+            sorted,
+            limit: HistoryLimit::default(),
+            started: false,
+            done: false,
+            sources: Vec::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn into_stream(self) -> impl Stream<Item = Result<Filing>> {
+        stream::unfold(self, |mut state| async move {
+            if state.done {
+                return None;
+            }
+
+            match state.next_filing().await {
+                Ok(Some(filing)) => Some((Ok(filing), state)),
+                Ok(None) => None,
+                Err(e) => {
+                    state.done = true;
+                    Some((Err(e), state))
+                }
+            }
+        })
+    }
+
+    async fn next_filing(&mut self) -> Result<Option<Filing>> {
+        if !self.started {
+            let submissions = get_submissions(&self.client, &self.cik).await?;
+            self.cik = submissions.cik.clone();
+            self.pending = submissions.filings.files.iter().cloned().collect();
+            let limited = self.limit != HistoryLimit::default();
+            if self.sorted || limited {
+                // Newest archives first, so the front is always the next one the merge can reach
+                self.pending
+                    .make_contiguous()
+                    .sort_by_key(|file| std::cmp::Reverse(archive_end(file)));
+            }
+            if let Some(since) = self.limit.since {
+                let since = since.and_hms_opt(0, 0, 0).map(|start| start.and_utc());
+                self.pending
+                    .retain(|file| archive_end(file).is_none_or(|end| Some(end) > since));
+            }
+            if let Some(max_archives) = self.limit.max_archives {
+                self.pending.truncate(max_archives);
+            }
+            self.push_source(submissions.recent_filings());
+            self.started = true;
+        }
+
+        loop {
+            let newest = self.newest_head();
+
+            let next_archive = match (self.pending.front(), newest) {
+                (None, _) => None,
+                (Some(_), None) => self.pending.pop_front(),
+                // In sorted mode an archive may hold filings newer than the current head
+                (Some(file), Some((_, head))) if self.sorted && archive_end(file).is_none_or(|end| end > head) => {
+                    self.pending.pop_front()
+                }
+                (Some(_), Some(_)) => None,
+            };
+
+            if let Some(file) = next_archive {
+                let archive = get_filings_archive(&self.client, &file).await?;
+                self.push_source(archive.to_filings(&self.cik));
+                continue;
+            }
+
+            return Ok(newest.and_then(|(i, _)| self.sources[i].pop_front()));
+        }
+    }
+
+    fn push_source(&mut self, mut filings: Vec<Filing>) {
+        filings.retain(|filing| self.limit.admits(filing));
+        if self.sorted {
+            filings.sort_by_key(|filing| std::cmp::Reverse(filing.acceptance_date));
+        }
+        self.sources.retain(|source| !source.is_empty());
+        self.sources.push(filings.into());
+    }
+
+    /// Index and acceptance date of the source whose head is next in order.
+    ///
+    /// Unsorted streams drain sources in the order they were loaded.
+    fn newest_head(&self) -> Option<(usize, DateTime<Utc>)> {
+        let heads = self
+            .sources
+            .iter()
+            .enumerate()
+            .filter_map(|(i, source)| source.front().map(|filing| (i, filing.acceptance_date)));
+
+        if self.sorted {
+            heads.max_by_key(|&(_, date)| date)
+        } else {
+            heads.min_by_key(|&(i, _)| i)
+        }
+    }
+}
+
+/// Latest possible acceptance time of filings in an archive.
+///
+/// A filing is accepted no later than the end of its filing date.
+fn archive_end(file: &ArchiveFile) -> Option<DateTime<Utc>> {
+    let last_day = NaiveDate::parse_from_str(&file.filing_to, "%Y-%m-%d").ok()?;
+    Some(last_day.checked_add_days(Days::new(1))?.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use futures::TryStreamExt;
+    use serde_json::json;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Columnar filings payload for `(accession, acceptance time)` pairs.
+    fn filings_json(filings: &[(&str, &str)]) -> serde_json::Value {
+        json!({
+            "accessionNumber": filings.iter().map(|f| f.0).collect::<Vec<_>>(),
+            "form": filings.iter().map(|_| "8-K").collect::<Vec<_>>(),
+            "primaryDocument": filings.iter().map(|_| "doc.htm").collect::<Vec<_>>(),
+            "acceptanceDateTime": filings.iter().map(|f| f.1).collect::<Vec<_>>(),
+        })
+    }
+
+    async fn mock_company_with_archive() -> MockServer {
+        let server = MockServer::start().await;
+
+        let submissions = json!({
+            "cik": "0000000042",
+            "entityType": "operating",
+            "sic": "3571",
+            "sicDescription": "Electronic Computers",
+            "name": "Test Co",
+            "tickers": [],
+            "exchanges": [],
+            "filings": {
+                "recent": filings_json(&[
+                    ("0000000042-24-000002", "2024-01-10T16:00:00.000Z"),
+                    ("0000000042-20-000001", "2020-06-01T16:00:00.000Z"),
+                ]),
+                "files": [{
+                    "name": "CIK0000000042-submissions-001.json",
+                    "filingCount": 2,
+                    "filingFrom": "2019-05-01",
+                    "filingTo": "2021-03-15",
+                }]
+            }
+        });
+        let archive = filings_json(&[
+            ("0000000042-21-000003", "2021-03-15T16:00:00.000Z"),
+            ("0000000042-19-000004", "2019-05-01T16:00:00.000Z"),
+        ]);
+
+        Mock::given(path("/submissions/CIK0000000042.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(submissions))
+            .mount(&server)
+            .await;
+        Mock::given(path("/submissions/CIK0000000042-submissions-001.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(archive))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    fn accessions(filings: &[Filing]) -> Vec<&str> {
+        filings.iter().map(|f| f.accession_number.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_merged_stream_limited_skips_extra_archives() {
+        let server = mock_company_with_archive().await;
+        Mock::given(path("/submissions/CIK0000000042.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "cik": "0000000042",
+                "entityType": "operating",
+                "sic": "3571",
+                "sicDescription": "Electronic Computers",
+                "name": "Test Co",
+                "tickers": [],
+                "exchanges": [],
+                "filings": {
+                    "recent": filings_json(&[("0000000042-24-000002", "2024-01-10T16:00:00.000Z")]),
+                    "files": [
+                        {
+                            "name": "CIK0000000042-submissions-002.json",
+                            "filingCount": 1,
+                            "filingFrom": "2010-01-01",
+                            "filingTo": "2012-12-31",
+                        },
+                        {
+                            "name": "CIK0000000042-submissions-001.json",
+                            "filingCount": 2,
+                            "filingFrom": "2019-05-01",
+                            "filingTo": "2021-03-15",
+                        },
+                    ]
+                }
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/submissions/CIK0000000042-submissions-002.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(filings_json(&[("0000000042-12-000005", "2012-12-31T16:00:00.000Z")])),
+            )
+            .expect(0)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let limit = HistoryLimit {
+            max_archives: Some(1),
+            ..Default::default()
+        };
+        let filings: Vec<Filing> = merged_filings_stream_limited(&client, "42", limit)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            accessions(&filings),
+            vec!["0000000042-24-000002", "0000000042-21-000003", "0000000042-19-000004",]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merged_stream_limited_since_prunes_archives_and_filings() {
+        let server = mock_company_with_archive().await;
+        Mock::given(path("/submissions/CIK0000000042.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "cik": "0000000042",
+                "entityType": "operating",
+                "sic": "3571",
+                "sicDescription": "Electronic Computers",
+                "name": "Test Co",
+                "tickers": [],
+                "exchanges": [],
+                "filings": {
+                    "recent": filings_json(&[
+                        ("0000000042-24-000002", "2024-01-10T16:00:00.000Z"),
+                        ("0000000042-20-000001", "2020-06-01T16:00:00.000Z"),
+                        ("0000000042-19-000006", "2019-12-31T16:00:00.000Z"),
+                    ]),
+                    "files": [
+                        {
+                            "name": "CIK0000000042-submissions-002.json",
+                            "filingCount": 1,
+                            "filingFrom": "2010-01-01",
+                            "filingTo": "2012-12-31",
+                        },
+                        {
+                            "name": "CIK0000000042-submissions-001.json",
+                            "filingCount": 2,
+                            "filingFrom": "2019-05-01",
+                            "filingTo": "2021-03-15",
+                        },
+                    ]
+                }
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/submissions/CIK0000000042-submissions-002.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(filings_json(&[("0000000042-12-000005", "2012-12-31T16:00:00.000Z")])),
+            )
+            .expect(0)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let limit = HistoryLimit {
+            since: NaiveDate::from_ymd_opt(2020, 1, 1),
+            ..Default::default()
+        };
+        let filings: Vec<Filing> = merged_filings_stream_limited(&client, "42", limit)
+            .try_collect()
+            .await
+            .unwrap();
+
+        // The 2010-2012 archive ends before `since` and is never requested;
+        // filings accepted before 2020 are dropped from the other sources
+        assert_eq!(
+            accessions(&filings),
+            vec!["0000000042-24-000002", "0000000042-20-000001", "0000000042-21-000003"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merged_stream_sorted_global_order() {
+        let server = mock_company_with_archive().await;
+        let client = mock_client(&server);
+
+        let filings: Vec<Filing> = merged_filings_stream_sorted(&client, "42").try_collect().await.unwrap();
+
+        assert_eq!(
+            accessions(&filings),
+            vec![
+                "0000000042-24-000002",
+                "0000000042-21-000003",
+                "0000000042-20-000001",
+                "0000000042-19-000004",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merged_stream_unsorted_recent_then_archives() {
+        let server = mock_company_with_archive().await;
+        let client = mock_client(&server);
+
+        let filings: Vec<Filing> = merged_filings_stream(&client, "42").try_collect().await.unwrap();
+
+        assert_eq!(
+            accessions(&filings),
+            vec![
+                "0000000042-24-000002",
+                "0000000042-20-000001",
+                "0000000042-21-000003",
+                "0000000042-19-000004",
+            ]
+        );
+        assert_eq!(filings[2].cik, "0000000042");
+    }
+}
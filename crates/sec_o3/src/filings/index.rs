@@ -0,0 +1,227 @@
+//! Filing directory listings.
+//!
+//! Every filing directory under `Archives/edgar/data` serves an
+//! `index.json` listing the files it contains, which is the most reliable
+//! way to find documents when submissions metadata is incomplete.
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+use super::Filing;
+use crate::parse::header::parse_sec_header;
+use crate::utils::{build_filing_dir_url, build_index_json_url, normalize_cik, AccessionNumber};
+use crate::{Client, Error, Result};
+
+/// Rendered XBRL report pages (e.g., `R1.htm`, `R12.htm`).
+static REPORT_PAGE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^R\d+\.html?$").unwrap());
+
+/// Contents of a filing directory's `index.json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct FilingIndex {
+    /// The listed directory
+    pub directory: Directory,
+}
+
+/// A directory in the EDGAR archives
+#[derive(Debug, Clone, Deserialize)]
+pub struct Directory {
+    /// Directory path (e.g., "/Archives/edgar/data/320193/000032019323000106")
+    pub name: String,
+    /// Files and subdirectories
+    #[serde(default)]
+    pub item: Vec<DirectoryItem>,
+}
+
+/// A file or subdirectory in a directory listing
+#[derive(Debug, Clone, Deserialize)]
+pub struct DirectoryItem {
+    /// File name
+    pub name: String,
+    /// Item type (e.g., "text.gif" for documents, "folder.gif" for directories)
+    #[serde(rename = "type", default)]
+    pub item_type: String,
+    /// Size in bytes as reported by EDGAR (empty for directories)
+    #[serde(default)]
+    pub size: String,
+    /// Last modification time (e.g., "2023-11-03 06:04:26")
+    #[serde(rename = "last-modified", default)]
+    pub last_modified: String,
+}
+
+impl DirectoryItem {
+    /// Size in bytes, if EDGAR reported one.
+    pub fn size_bytes(&self) -> Option<u64> {
+        self.size.trim().parse().ok()
+    }
+}
+
+impl FilingIndex {
+    /// Best guess at the main HTML document of the filing.
+    ///
+    /// Picks the largest `.htm`/`.html` file that is not an index page or a
+    /// rendered financial report (`R1.htm`, ...).
+    pub fn main_document(&self) -> Option<&DirectoryItem> {
+        self.directory
+            .item
+            .iter()
+            .filter(|item| {
+                let name = item.name.to_ascii_lowercase();
+                (name.ends_with(".htm") || name.ends_with(".html"))
+                    && !name.contains("-index")
+                    && !REPORT_PAGE.is_match(&name)
+            })
+            .max_by_key(|item| item.size_bytes().unwrap_or(0))
+    }
+}
+
+/// Fetch the `index.json` directory listing of a filing.
+pub async fn get_filing_index(client: &Client, filing: &Filing) -> Result<FilingIndex> {
+    client
+        .get_json(&build_index_json_url(&filing.cik, &filing.accession_number))
+        .await
+}
+
+/// Fetch a single filing by CIK and accession number.
+///
+/// Builds the [`Filing`] from the directory's `index.json` and the SEC
+/// header (`<accession>.hdr.sgml`), which gives the form type and acceptance
+/// time, so the company's full submissions JSON is never downloaded.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidCik` or `Error::Custom` for malformed identifiers,
+/// `Error::NotFound` if the filing does not exist, and `Error::Custom` if
+/// the header lacks the form type or acceptance time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::get_filing;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filing = get_filing(&client, "320193", "0000320193-23-000106").await?;
+///     println!("{} {}", filing.form_type, filing.primary_document_url());
+///     Ok(())
+/// }
+/// ```
+pub async fn get_filing(client: &Client, cik: &str, accession: &str) -> Result<Filing> {
+    let accession = AccessionNumber::parse(accession)?;
+    let cik = normalize_cik(cik)?;
+
+    let directory = build_filing_dir_url(&cik, &accession.dashed());
+    let index: FilingIndex = client
+        .get_json(&build_index_json_url(&cik, &accession.dashed()))
+        .await?;
+    let header = client
+        .get_text(&format!("{}{}.hdr.sgml", directory, accession.dashed()))
+        .await?;
+
+    let header = parse_sec_header(&header);
+    let form_type = header
+        .form_type
+        .ok_or_else(|| Error::Custom(format!("No form type in SEC header of {}", accession)))?;
+    let acceptance_date = header
+        .acceptance_datetime
+        .ok_or_else(|| Error::Custom(format!("No acceptance time in SEC header of {}", accession)))?;
+
+    let full_text = format!("{}.txt", accession.dashed());
+    let items = &index.directory.item;
+
+    Ok(Filing {
+        cik,
+        accession_number: accession.dashed(),
+        form_type,
+        acceptance_date,
+        primary_document: index.main_document().map(|item| item.name.clone()).unwrap_or_default(),
+        is_xbrl: items
+            .iter()
+            .any(|item| item.name == "FilingSummary.xml" || item.name.ends_with("_htm.xml")),
+        size: items
+            .iter()
+            .find(|item| item.name == full_text)
+            .and_then(DirectoryItem::size_bytes),
+        report_date: header.period_of_report,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use serde_json::json;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_main_document_skips_index_and_reports() {
+        let index: FilingIndex = serde_json::from_value(json!({
+            "directory": {
+                "name": "/Archives/edgar/data/320193/000032019323000106",
+                "parent-dir": "/Archives/edgar/data/320193",
+                "item": [
+                    { "last-modified": "2023-11-03 06:04:26", "name": "0000320193-23-000106-index.htm", "type": "text.gif", "size": "" },
+                    { "last-modified": "2023-11-03 06:04:26", "name": "R2.htm", "type": "text.gif", "size": "9000000" },
+                    { "last-modified": "2023-11-03 06:04:26", "name": "a10-kexhibit4.htm", "type": "text.gif", "size": "80000" },
+                    { "last-modified": "2023-11-03 06:04:26", "name": "aapl-20230930.htm", "type": "text.gif", "size": "1502000" },
+                    { "last-modified": "2023-11-03 06:04:26", "name": "Financial_Report.xlsx", "type": "text.gif", "size": "20000000" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(index.main_document().unwrap().name, "aapl-20230930.htm");
+        assert_eq!(index.directory.item[0].size_bytes(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_filing_from_index() {
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/edgar/data/320193/000032019323000106/index.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "directory": {
+                    "name": "/Archives/edgar/data/320193/000032019323000106",
+                    "item": [
+                        { "name": "0000320193-23-000106-index.htm", "type": "text.gif", "size": "" },
+                        { "name": "0000320193-23-000106.txt", "type": "text.gif", "size": "9658123" },
+                        { "name": "FilingSummary.xml", "type": "text.gif", "size": "80000" },
+                        { "name": "aapl-20230930.htm", "type": "text.gif", "size": "1502000" }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path(
+            "/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106.hdr.sgml",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<SEC-HEADER>0000320193-23-000106.hdr.sgml : 20231103\n\
+                 <ACCEPTANCE-DATETIME>20231102180827\n\
+                 ACCESSION NUMBER:\t\t0000320193-23-000106\n\
+                 CONFORMED SUBMISSION TYPE:\t10-K\n\
+                 PUBLIC DOCUMENT COUNT:\t\t96\n\
+                 </SEC-HEADER>\n",
+        ))
+        .mount(&server)
+        .await;
+        Mock::given(path("/submissions/CIK0000320193.json"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let filing = get_filing(&client, "0000320193", "000032019323000106").await.unwrap();
+
+        assert_eq!(filing.cik, "0000320193");
+        assert_eq!(filing.accession_number, "0000320193-23-000106");
+        assert_eq!(filing.form_type, "10-K");
+        assert_eq!(filing.acceptance_date.to_rfc3339(), "2023-11-02T18:08:27+00:00");
+        assert_eq!(filing.primary_document, "aapl-20230930.htm");
+        assert!(filing.is_xbrl);
+        assert_eq!(filing.reported_size(), Some(9_658_123));
+    }
+}
@@ -0,0 +1,87 @@
+//! Insider transaction dataset downloads.
+//!
+//! The SEC publishes the Forms 3, 4, and 5 filed each quarter as a ZIP
+//! archive of TSV files. Working from these archives avoids fetching and
+//! parsing every ownership filing one by one; once extracted, the
+//! `NONDERIV_TRANS.tsv` file can be read with
+//! [`parse_nonderiv_trans`](crate::parse::datasets::parse_nonderiv_trans).
+use std::path::{Path, PathBuf};
+
+use crate::utils::build_insider_dataset_url;
+use crate::{Client, Error, Result};
+
+/// Download the insider transactions dataset for a calendar quarter.
+///
+/// The archive is streamed into `output_dir` as `<year>q<quarter>_form345.zip`
+/// and is not extracted.
+///
+/// # Errors
+///
+/// Returns `Error::Custom` if `quarter` is not in `1..=4`, and
+/// `Error::NotFound` if the SEC has not published the dataset.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::download_insider_dataset;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///
+///     let path = download_insider_dataset(&client, 2023, 4, "datasets").await?;
+///     println!("Saved {}", path.display());
+///     Ok(())
+/// }
+/// ```
+pub async fn download_insider_dataset(
+    client: &Client,
+    year: u16,
+    quarter: u8,
+    output_dir: impl AsRef<Path>,
+) -> Result<PathBuf> {
+    if !(1..=4).contains(&quarter) {
+        return Err(Error::Custom(format!("Invalid quarter: {}", quarter)));
+    }
+
+    let output_dir = output_dir.as_ref();
+    tokio::fs::create_dir_all(output_dir).await.map_err(Error::IoError)?;
+
+    let output_path = output_dir.join(format!("{}q{}_form345.zip", year, quarter));
+    client
+        .download_streaming(&build_insider_dataset_url(year, quarter), &output_path)
+        .await?;
+
+    Ok(output_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_client;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_download_insider_dataset() {
+        let server = MockServer::start().await;
+        Mock::given(path(
+            "/files/structureddata/data/insider-transactions-data-sets/2023q4_form345.zip",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PK\x03\x04archive".to_vec()))
+        .mount(&server)
+        .await;
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+
+        let saved = download_insider_dataset(&client, 2023, 4, dir.path()).await.unwrap();
+
+        assert_eq!(saved, dir.path().join("2023q4_form345.zip"));
+        assert_eq!(std::fs::read(saved).unwrap(), b"PK\x03\x04archive");
+        assert!(matches!(
+            download_insider_dataset(&client, 2023, 5, dir.path()).await,
+            Err(Error::Custom(_))
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+//! Form 8-K item codes.
+//!
+//! The submissions API lists the items a current report discloses as a
+//! string such as `"2.02,9.01"`.
+use std::fmt;
+
+/// A Form 8-K item number (e.g., Item 2.02).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemCode {
+    /// Section number (the `2` in 2.02)
+    pub section: u8,
+    /// Item within the section (the `02` in 2.02)
+    pub subsection: u8,
+}
+
+impl ItemCode {
+    /// Title of the item as given in Form 8-K, for the common items.
+    pub fn description(&self) -> Option<&'static str> {
+        let description = match (self.section, self.subsection) {
+            (1, 1) => "Entry into a Material Definitive Agreement",
+            (1, 2) => "Termination of a Material Definitive Agreement",
+            (1, 3) => "Bankruptcy or Receivership",
+            (1, 5) => "Material Cybersecurity Incidents",
+            (2, 1) => "Completion of Acquisition or Disposition of Assets",
+            (2, 2) => "Results of Operations and Financial Condition",
+            (2, 3) => "Creation of a Direct Financial Obligation",
+            (2, 5) => "Costs Associated with Exit or Disposal Activities",
+            (2, 6) => "Material Impairments",
+            (3, 1) => "Notice of Delisting or Failure to Satisfy a Continued Listing Rule",
+            (3, 2) => "Unregistered Sales of Equity Securities",
+            (3, 3) => "Material Modification to Rights of Security Holders",
+            (4, 1) => "Changes in Registrant's Certifying Accountant",
+            (4, 2) => "Non-Reliance on Previously Issued Financial Statements",
+            (5, 1) => "Changes in Control of Registrant",
+            (5, 2) => "Departure or Election of Directors or Officers",
+            (5, 3) => "Amendments to Articles of Incorporation or Bylaws",
+            (5, 7) => "Submission of Matters to a Vote of Security Holders",
+            (7, 1) => "Regulation FD Disclosure",
+            (8, 1) => "Other Events",
+            (9, 1) => "Financial Statements and Exhibits",
+            _ => return None,
+        };
+        Some(description)
+    }
+}
+
+impl fmt::Display for ItemCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:02}", self.section, self.subsection)
+    }
+}
+
+/// Parse an 8-K items string (e.g., `"2.02,9.01"`) into item codes.
+///
+/// Items may be separated by commas and/or whitespace; empty entries and
+/// entries that are not `<section>.<item>` numbers are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::filings::parse_items;
+///
+/// let items = parse_items("2.02, 9.01,");
+/// assert_eq!(items.len(), 2);
+/// assert_eq!(items[0].to_string(), "2.02");
+/// assert_eq!(items[1].description(), Some("Financial Statements and Exhibits"));
+/// ```
+pub fn parse_items(items: &str) -> Vec<ItemCode> {
+    items
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(|item| {
+            let (section, subsection) = item.trim().split_once('.')?;
+            Some(ItemCode {
+                section: section.parse().ok()?,
+                subsection: subsection.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_item() {
+        let items = parse_items("5.07");
+        assert_eq!(
+            items,
+            vec![ItemCode {
+                section: 5,
+                subsection: 7
+            }]
+        );
+        assert_eq!(items[0].to_string(), "5.07");
+        assert_eq!(
+            items[0].description(),
+            Some("Submission of Matters to a Vote of Security Holders")
+        );
+    }
+
+    #[test]
+    fn test_parse_multiple_items() {
+        let items = parse_items(" 2.02,7.01 9.01, ,");
+        let codes: Vec<String> = items.iter().map(ItemCode::to_string).collect();
+        assert_eq!(codes, vec!["2.02", "7.01", "9.01"]);
+
+        assert!(parse_items("").is_empty());
+        assert_eq!(
+            parse_items("2.02,bogus,x.1"),
+            vec![ItemCode {
+                section: 2,
+                subsection: 2
+            }]
+        );
+        assert_eq!(
+            ItemCode {
+                section: 6,
+                subsection: 4
+            }
+            .description(),
+            None
+        );
+    }
+}
@@ -0,0 +1,1824 @@
+//! SEC EDGAR filing downloads and metadata retrieval.
+//!
+//! This module provides functions to:
+//! - Fetch company submission history
+//! - Download specific filing documents (XML, HTML, text)
+//! - Parse filing metadata and document URLs
+//!
+//! # Submodules
+//!
+//! * [`crawl`] - Concurrent submissions fetches over many CIKs
+//! * [`current`] - Same-day filings from the current events feed
+//! * [`form`] - Form type classification
+//! * [`history`] - Older filings from submission archive files
+//! * [`index`] - Filing directory listings (`index.json`)
+//! * [`insider`] - Quarterly insider transaction dataset downloads
+//! * [`items`] - Form 8-K item codes
+//! * [`schedule`] - Expected dates of periodic filings
+//! * [`search`] - Company name search
+//! * [`sic`] - Standard Industrial Classification lookups
+//! * `sqlite` - Filing metadata in a local SQLite table (`sqlite` feature)
+//! * [`xbrl`] - Validated XBRL instance downloads
+pub mod crawl;
+pub mod current;
+pub mod form;
+pub mod history;
+pub mod index;
+pub mod insider;
+pub mod items;
+pub mod schedule;
+pub mod search;
+pub mod sic;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod xbrl;
+
+pub use crawl::crawl_submissions;
+pub use current::{current_filings, latest_filings_by_form, parse_current_feed, FeedEntry};
+pub use form::{amends, FormType};
+pub use history::{
+    get_filings_archive, merged_filings_stream, merged_filings_stream_limited, merged_filings_stream_sorted,
+    HistoryLimit,
+};
+pub use index::{get_filing, get_filing_index, FilingIndex};
+pub use insider::download_insider_dataset;
+pub use items::{parse_items, ItemCode};
+pub use schedule::{is_overdue, next_expected_filing, FilingFrequency};
+pub use search::{parse_company_search, search_companies, CompanyMatch};
+#[cfg(feature = "sqlite")]
+pub use sqlite::ingest_filings_to_sqlite;
+pub use xbrl::{download_xbrl_instance, download_xbrl_many, download_xbrl_to_file, xbrl_coverage};
+
+use crate::facts::fiscal_quarter;
+use crate::parse::filing_summary::{self, FilingSummary};
+use crate::utils::{
+    build_filing_dir_url, build_filing_url, build_full_text_url, build_public_filing_url, filing_id, normalize_cik,
+    AccessionNumber,
+};
+use crate::{Client, Error, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tokio_util::sync::CancellationToken;
+
+/// Company submissions metadata from SEC API
+///
+/// Contains comprehensive information about a company including
+/// identifying information and filing history.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Submissions {
+    /// Company's Central Index Key (10-digit format)
+    pub cik: String,
+    /// Entity type (e.g., "operating", "foreign")
+    pub entity_type: String,
+    /// Standard Industrial Classification code
+    pub sic: String,
+    /// Human-readable description of the SIC code
+    pub sic_description: String,
+    /// Company name as registered with the SEC
+    pub name: String,
+    /// List of ticker symbols (can be multiple or empty)
+    pub tickers: Vec<String>,
+    /// List of exchanges where the company is listed
+    pub exchanges: Vec<String>,
+    /// Fiscal year end as MMDD (e.g., "0930"), when reported
+    #[serde(default)]
+    pub fiscal_year_end: Option<String>,
+    /// Names the company previously filed under
+    #[serde(default)]
+    pub former_names: Vec<FormerName>,
+    /// CIK originally requested, when EDGAR redirected to a successor entity
+    #[serde(skip)]
+    pub redirected_from: Option<String>,
+    /// Filing history for this company
+    pub filings: Filings,
+}
+
+impl Submissions {
+    /// Calendar month (1–12) in which the fiscal year ends.
+    ///
+    /// Parsed from the MMDD [`fiscal_year_end`](Self::fiscal_year_end) value,
+    /// for use with [`fiscal_quarter`](crate::facts::fiscal_quarter). Returns
+    /// `None` if the value is missing or malformed.
+    pub fn fiscal_year_end_month(&self) -> Option<u32> {
+        let mmdd = self.fiscal_year_end.as_deref()?.trim();
+        if mmdd.len() != 4 || !mmdd.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let month: u32 = mmdd[..2].parse().ok()?;
+        let day: u32 = mmdd[2..].parse().ok()?;
+        ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(month)
+    }
+
+    /// Whether the company facts API likely has data for this entity.
+    ///
+    /// The facts endpoint 404s for filers that never submitted XBRL, such
+    /// as individuals and most funds, so callers can check this before
+    /// [`get_company_facts`](crate::facts::get_company_facts). Individuals
+    /// never have facts; anyone else does if a recent filing carries XBRL
+    /// or Inline XBRL.
+    pub fn likely_has_xbrl_facts(&self) -> bool {
+        if self.entity_type.eq_ignore_ascii_case("individual") {
+            return false;
+        }
+
+        let recent = &self.filings.recent;
+        recent
+            .is_xbrl
+            .iter()
+            .chain(&recent.is_inline_xbrl)
+            .any(|&flag| flag != 0)
+    }
+
+    /// Company name in effect on `date`.
+    ///
+    /// A former name applies through its `to` date, so on the day of a
+    /// change the old name is returned. Dates before the earliest former
+    /// name resolve to that name; dates after the last one resolve to the
+    /// current [`name`](Self::name). A former name without a `to` date runs
+    /// until the `from` date of the next former name; if there is none, it
+    /// is skipped.
+    pub fn name_at(&self, date: NaiveDate) -> &str {
+        self.former_names
+            .iter()
+            .filter_map(|former| Some((self.former_name_end(former)?, former)))
+            .filter(|&(to, _)| to >= date)
+            .min_by_key(|&(to, _)| to)
+            .map_or(&self.name, |(_, former)| &former.name)
+    }
+
+    /// Last date `former` was in effect: its `to` date, or the `from` date of
+    /// the former name that followed it.
+    fn former_name_end(&self, former: &FormerName) -> Option<NaiveDate> {
+        former.to.or_else(|| {
+            let from = former.from?;
+            self.former_names
+                .iter()
+                .filter_map(|other| other.from)
+                .filter(|&next| next > from)
+                .min()
+        })
+    }
+
+    /// Build [`Filing`] structs from the recent filings arrays.
+    ///
+    /// See [`RecentFilings::to_filings`] for which entries are skipped.
+    pub fn recent_filings(&self) -> Vec<Filing> {
+        self.filings.recent.to_filings(&self.cik)
+    }
+
+    /// Sum of the reported sizes of all recent filings, in bytes.
+    pub fn total_recent_size(&self) -> u64 {
+        self.filings
+            .recent
+            .size
+            .iter()
+            .filter_map(|&size| u64::try_from(size).ok())
+            .sum()
+    }
+
+    /// Distinct form types among recent filings with their counts.
+    ///
+    /// Sorted by count descending, then by form type for equal counts.
+    pub fn distinct_forms(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for form in self.filings.recent.form.iter().filter(|f| !f.is_empty()) {
+            *counts.entry(form.as_str()).or_default() += 1;
+        }
+
+        let mut forms: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(form, count)| (form.to_string(), count))
+            .collect();
+        forms.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        forms
+    }
+
+    /// Recent filings grouped by the year of their acceptance date.
+    ///
+    /// Filings within each year are ordered newest first.
+    pub fn filings_by_year(&self) -> BTreeMap<i32, Vec<Filing>> {
+        let mut years: BTreeMap<i32, Vec<Filing>> = BTreeMap::new();
+        for filing in self.recent_filings() {
+            years.entry(filing.acceptance_date.year()).or_default().push(filing);
+        }
+
+        for filings in years.values_mut() {
+            filings.sort_by_key(|filing| std::cmp::Reverse(filing.acceptance_date));
+        }
+        years
+    }
+}
+
+/// Filing history for a company
+///
+/// Contains recent filings (at least one year, or the last 1,000) and
+/// references to archive files holding older filings.
+#[derive(Debug, Deserialize)]
+pub struct Filings {
+    /// Recent filings data
+    pub recent: RecentFilings,
+    /// Archive files with older filings, fetched separately
+    #[serde(default)]
+    pub files: Vec<ArchiveFile>,
+}
+
+/// Reference to an archive file of older filings
+///
+/// Archive files use the same columnar layout as [`RecentFilings`] and
+/// are served from `https://data.sec.gov/submissions/{name}`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveFile {
+    /// File name (e.g., "CIK0000320193-submissions-001.json")
+    pub name: String,
+    /// Number of filings in the file
+    pub filing_count: u64,
+    /// Earliest filing date in YYYY-MM-DD format
+    pub filing_from: String,
+    /// Latest filing date in YYYY-MM-DD format
+    pub filing_to: String,
+}
+
+/// A name the company previously filed under
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FormerName {
+    /// Former company name
+    pub name: String,
+    /// First day the name was in use, if EDGAR reports a valid date
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub from: Option<NaiveDate>,
+    /// Last day the name was in use, if EDGAR reports a valid date
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub to: Option<NaiveDate>,
+}
+
+/// Deserialize the date part of an ISO 8601 timestamp (e.g., "2007-01-04T05:00:00.000Z").
+///
+/// Null, empty, and malformed values become `None`, so one bad date does
+/// not fail the whole submissions response.
+fn deserialize_date<'de, D>(deserializer: D) -> std::result::Result<Option<NaiveDate>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    let value = value.trim();
+    let date = value.get(..10).unwrap_or(value);
+    Ok(NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+/// Recent filings data
+///
+/// All vectors have the same length, with indices corresponding
+/// to individual filings. Each index represents one filing.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentFilings {
+    /// Accession numbers (e.g., "0000320193-23-000106")
+    pub accession_number: Vec<String>,
+    /// Filing dates in YYYY-MM-DD format
+    #[serde(default)]
+    pub filing_date: Vec<String>,
+    /// Report period end dates in YYYY-MM-DD format
+    #[serde(default)]
+    pub report_date: Vec<String>,
+    /// Acceptance timestamps (ISO 8601 format)
+    #[serde(default)]
+    pub acceptance_date_time: Vec<String>,
+    /// Securities Act under which filed (e.g., "33", "34")
+    #[serde(default)]
+    pub act: Vec<String>,
+    /// Form types (e.g., "10-K", "8-K", "DEF 14A")
+    #[serde(default)]
+    pub form: Vec<String>,
+    /// SEC file numbers
+    #[serde(default)]
+    pub file_number: Vec<String>,
+    /// Film numbers (legacy identifier)
+    #[serde(default)]
+    pub film_number: Vec<String>,
+    /// Items disclosed (for 8-K filings)
+    #[serde(default)]
+    pub items: Vec<String>,
+    /// Filing sizes in bytes
+    #[serde(default)]
+    pub size: Vec<i64>,
+    /// Whether filing contains XBRL data (1 = yes, 0 = no)
+    #[serde(rename(deserialize = "isXBRL"))]
+    #[serde(default)]
+    pub is_xbrl: Vec<i32>,
+    /// Whether filing contains Inline XBRL (1 = yes, 0 = no)
+    #[serde(rename(deserialize = "isInlineXBRL"))]
+    #[serde(default)]
+    pub is_inline_xbrl: Vec<i32>,
+    /// Primary document filename (e.g., "aapl-20230930.htm")
+    #[serde(default)]
+    pub primary_document: Vec<String>,
+    /// Description of primary document
+    #[serde(default)]
+    pub primary_doc_description: Vec<String>,
+}
+
+impl RecentFilings {
+    /// Check that the columnar arrays line up.
+    ///
+    /// Arrays absent from the payload deserialize empty and are accepted;
+    /// any other array must have one entry per accession number.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` naming the first array whose length differs.
+    pub fn validate_lengths(&self) -> Result<()> {
+        let expected = self.accession_number.len();
+        let lengths = [
+            ("filingDate", self.filing_date.len()),
+            ("reportDate", self.report_date.len()),
+            ("acceptanceDateTime", self.acceptance_date_time.len()),
+            ("act", self.act.len()),
+            ("form", self.form.len()),
+            ("fileNumber", self.file_number.len()),
+            ("filmNumber", self.film_number.len()),
+            ("items", self.items.len()),
+            ("size", self.size.len()),
+            ("isXBRL", self.is_xbrl.len()),
+            ("isInlineXBRL", self.is_inline_xbrl.len()),
+            ("primaryDocument", self.primary_document.len()),
+            ("primaryDocDescription", self.primary_doc_description.len()),
+        ];
+
+        match lengths.iter().find(|&&(_, len)| len != 0 && len != expected) {
+            Some((name, len)) => Err(Error::Custom(format!(
+                "Malformed filings data: `{}` has {} entries but there are {} accession numbers",
+                name, len, expected
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Build [`Filing`] structs from the columnar arrays.
+    ///
+    /// Entries without a primary document, form type, or valid acceptance
+    /// timestamp are skipped.
+    pub fn to_filings(&self, cik: &str) -> Vec<Filing> {
+        (0..self.accession_number.len())
+            .filter_map(|i| {
+                // Filter out empty values
+                let primary_document = self.primary_document.get(i)?.clone();
+                let form_type = self.form.get(i).cloned().unwrap_or_default();
+                if primary_document.is_empty() || form_type.is_empty() {
+                    return None;
+                }
+
+                // Ensure acceptance_date is a valid UTC string
+                let acceptance_date = self.acceptance_date_time.get(i)?.parse::<DateTime<Utc>>().ok()?;
+
+                Some(Filing {
+                    cik: cik.to_string(),
+                    accession_number: self.accession_number[i].clone(),
+                    form_type,
+                    acceptance_date,
+                    primary_document,
+                    is_xbrl: self.is_xbrl.get(i).copied().unwrap_or(0) == 1,
+                    size: self.size.get(i).and_then(|&size| u64::try_from(size).ok()),
+                    report_date: self
+                        .report_date
+                        .get(i)
+                        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()),
+                })
+            })
+            .collect()
+    }
+
+    /// Filings whose accession numbers are not in `seen`, newest first.
+    ///
+    /// For incremental sync against a stored set of accession numbers,
+    /// dashed or not. Entries are built and skipped as in
+    /// [`to_filings`](Self::to_filings).
+    pub fn new_accessions(&self, cik: &str, seen: &HashSet<String>) -> Vec<Filing> {
+        let seen: HashSet<String> = seen.iter().map(|accession| accession.trim().replace('-', "")).collect();
+
+        self.to_filings(cik)
+            .into_iter()
+            .filter(|filing| !seen.contains(&filing.identity().1))
+            .collect()
+    }
+}
+
+/// A specific filing document
+///
+/// Represents a single SEC filing with methods to construct
+/// URLs for downloading documents.
+///
+/// Two filings are equal when they have the same CIK and accession number,
+/// ignoring CIK zero-padding and accession dashes, so filings from different
+/// sources can be deduplicated in a `HashSet`.
+#[derive(Debug, Clone)]
+pub struct Filing {
+    /// Company's Central Index Key
+    pub cik: String,
+    /// Unique filing identifier (e.g., "0000320193-23-000106")
+    pub accession_number: String,
+    /// Form type (e.g., "10-K", "8-K")
+    pub form_type: String,
+    /// Acceptance date as a chrono::DateTime
+    pub acceptance_date: DateTime<Utc>,
+    /// Primary document filename
+    pub primary_document: String,
+    /// Whether this filing contains XBRL data
+    pub is_xbrl: bool,
+    /// Filing size in bytes, as reported in submissions metadata
+    pub size: Option<u64>,
+    /// End of the period the filing reports on, when known
+    pub report_date: Option<NaiveDate>,
+}
+
+impl PartialEq for Filing {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for Filing {}
+
+impl Hash for Filing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+impl Filing {
+    /// Normalized `(cik, accession)` pair identifying the filing.
+    fn identity(&self) -> (&str, String) {
+        let cik = self.cik.trim();
+        let cik = cik.strip_prefix("CIK").unwrap_or(cik).trim_start_matches('0');
+        (cik, self.accession_number.trim().replace('-', ""))
+    }
+
+    /// Fiscal period the filing reports on, for display (e.g., "Q3 FY2023").
+    ///
+    /// The quarter and fiscal year come from [`report_date`](Self::report_date)
+    /// as in [`fiscal_quarter`](crate::facts::fiscal_quarter). Annual reports
+    /// (10-K, 20-F, 40-F and their variants) are labeled with the year alone,
+    /// e.g. "FY2023". Returns `None` if the report date is unknown or
+    /// `fiscal_year_end_month` is not in `1..=12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Utc};
+    /// use sec_o3::filings::Filing;
+    ///
+    /// let filing = Filing {
+    ///     cik: "0000320193".to_string(),
+    ///     accession_number: "0000320193-23-000077".to_string(),
+    ///     form_type: "10-Q".to_string(),
+    ///     acceptance_date: Utc::now(),
+    ///     primary_document: "aapl-20230701.htm".to_string(),
+    ///     is_xbrl: true,
+    ///     size: None,
+    ///     report_date: NaiveDate::from_ymd_opt(2023, 7, 1),
+    /// };
+    ///
+    /// assert_eq!(filing.period_label(9).as_deref(), Some("Q3 FY2023"));
+    /// ```
+    pub fn period_label(&self, fiscal_year_end_month: u32) -> Option<String> {
+        let (year, quarter) = fiscal_quarter(self.report_date?, fiscal_year_end_month)?;
+        let annual = matches!(
+            FormType::new(&self.form_type).base(),
+            "10-K" | "10-K405" | "10-KT" | "10-KSB" | "20-F" | "40-F"
+        );
+        Some(if annual {
+            format!("FY{}", year)
+        } else {
+            format!("Q{} FY{}", quarter, year)
+        })
+    }
+
+    /// Get the filing size in bytes reported by the SEC, without downloading it.
+    pub fn reported_size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Time elapsed between acceptance and `as_of`.
+    ///
+    /// Negative if the filing was accepted after `as_of`.
+    pub fn age(&self, as_of: DateTime<Utc>) -> Duration {
+        as_of - self.acceptance_date
+    }
+
+    /// Whether the filing was accepted within `within` before `as_of`.
+    ///
+    /// Filings accepted after `as_of` are not considered recent.
+    pub fn is_recent(&self, as_of: DateTime<Utc>, within: Duration) -> bool {
+        let age = self.age(as_of);
+        age >= Duration::zero() && age <= within
+    }
+
+    /// Canonical key of the filing, e.g. `0000320193-000032019323000106`.
+    ///
+    /// See [`crate::utils::filing_id`].
+    pub fn filing_id(&self) -> String {
+        filing_id(&self.cik, &self.accession_number)
+    }
+
+    /// Get the base URL for this filing's documents
+    pub fn base_url(&self) -> String {
+        build_filing_dir_url(&self.cik, &self.accession_number)
+    }
+
+    /// Get the URL for the primary document
+    pub fn primary_document_url(&self) -> String {
+        format!("{}{}", self.base_url(), self.primary_document)
+    }
+
+    /// Get the URL of the filing's main document.
+    ///
+    /// Uses `primary_document` when the submissions metadata provides one.
+    /// Otherwise fetches the directory's `index.json` and picks the largest
+    /// HTML document that is not an index page or rendered report.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the directory has no candidate document.
+    pub async fn best_document_url(&self, client: &Client) -> Result<String> {
+        if !self.primary_document.trim().is_empty() {
+            return Ok(self.primary_document_url());
+        }
+
+        let index = get_filing_index(client, self).await?;
+        let document = index
+            .main_document()
+            .ok_or_else(|| Error::NotFound(format!("No main document found for filing {}", self.accession_number)))?;
+
+        Ok(format!("{}{}", self.base_url(), document.name))
+    }
+
+    /// Get the URL for the filing's `FilingSummary.xml` (XBRL filings only)
+    pub fn filing_summary_url(&self) -> String {
+        format!("{}FilingSummary.xml", self.base_url())
+    }
+
+    /// Get the URL for the full submission text file
+    pub fn submission_text_url(&self) -> String {
+        build_full_text_url(&self.cik, &self.accession_number)
+    }
+
+    /// Get the URL for the filing's `-index.html` page
+    pub fn index_url(&self) -> String {
+        build_filing_url(&self.cik, &self.accession_number)
+    }
+
+    /// Get the public URL of the filing's index page, for citations
+    ///
+    /// See [`build_public_filing_url`].
+    pub fn public_url(&self) -> String {
+        build_public_filing_url(&self.cik, &self.accession_number)
+    }
+
+    /// Parse this filing's accession number, accepting dashed or undashed forms.
+    pub fn accession(&self) -> Result<AccessionNumber> {
+        AccessionNumber::parse(&self.accession_number)
+    }
+}
+
+/// Fetch company submission history by CIK
+///
+/// Returns metadata about the company and all their recent filings.
+///
+/// If EDGAR redirects the request to another submissions file, as it can
+/// for merged or renamed entities, the successor's submissions are fetched
+/// instead and the requested CIK is recorded in
+/// [`Submissions::redirected_from`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::get_submissions;
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let submissions = get_submissions(&client, "0000320193").await?;
+///
+///     println!("Company: {}", submissions.name);
+///     println!("Recent filings: {}", submissions.filings.recent.form.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn get_submissions(client: &Client, cik: &str) -> Result<Submissions> {
+    match fetch_submissions(client, cik).await {
+        Err(Error::Redirect(location)) => {
+            let successor = successor_cik(&location).ok_or(Error::Redirect(location))?;
+            let mut submissions = fetch_submissions(client, &successor).await?;
+            submissions.redirected_from = Some(cik.to_string());
+            Ok(submissions)
+        }
+        result => result,
+    }
+}
+
+/// Fetch and validate the submissions JSON of exactly `cik`.
+async fn fetch_submissions(client: &Client, cik: &str) -> Result<Submissions> {
+    let cik_padded = format!("CIK{:0>10}", cik.trim_start_matches("CIK"));
+    let url = format!("https://data.sec.gov/submissions/{}.json", cik_padded);
+
+    let body = client.get_json_body(&url, client.retry_policy()).await?;
+
+    let submissions: Submissions = serde_json::from_slice(&body).map_err(Error::JsonError)?;
+    submissions.filings.recent.validate_lengths()?;
+    check_cik_matches(&submissions.cik, cik)?;
+
+    Ok(submissions)
+}
+
+/// CIK of the submissions file a redirect points to, if it points to one.
+fn successor_cik(location: &str) -> Option<String> {
+    let path = location.split(['?', '#']).next()?;
+    let file = path.rsplit('/').next()?;
+    let digits = file.strip_prefix("CIK")?.strip_suffix(".json")?;
+
+    (digits.len() == 10 && digits.bytes().all(|b| b.is_ascii_digit())).then(|| digits.to_string())
+}
+
+/// Reject submissions belonging to a different company than requested.
+///
+/// A redirect or stale URL can occasionally serve another company's
+/// submissions, so both CIKs are normalized and compared. A returned CIK
+/// that does not normalize never matches.
+fn check_cik_matches(returned: &str, requested: &str) -> Result<()> {
+    if let (Ok(returned), Ok(requested)) = (normalize_cik(returned), normalize_cik(requested)) {
+        if returned == requested {
+            return Ok(());
+        }
+    }
+
+    Err(Error::Custom(format!(
+        "CIK mismatch: requested {}, received {}",
+        requested, returned
+    )))
+}
+
+/// Get a list of recent filings for a company
+///
+/// Returns Filing structs for easy access to document URLs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::get_recent_filings;
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     for filing in filings.iter().take(5) {
+///         println!("{} - {} on {}", filing.form_type, filing.primary_document, filing.filing_date);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn get_recent_filings(client: &Client, cik: &str) -> Result<Vec<Filing>> {
+    let submissions = get_submissions(client, cik).await?;
+    Ok(submissions.recent_filings())
+}
+
+/// Fetch and parse a filing's `FilingSummary.xml`
+///
+/// Lists the rendered R-reports and the files in the XBRL package.
+/// Only XBRL filings have a filing summary.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::{get_recent_filings, get_filing_summary};
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     if let Some(filing) = filings.iter().find(|f| f.is_xbrl) {
+///         let summary = get_filing_summary(&client, filing).await?;
+///         println!("XBRL package files: {:?}", summary.input_files);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn get_filing_summary(client: &Client, filing: &Filing) -> Result<FilingSummary> {
+    let xml = client.get_text(&filing.filing_summary_url()).await?;
+    filing_summary::parse(&xml)
+}
+
+/// Download a filing document (XML, HTML, or text)
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::{get_recent_filings, download_filing};
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     if let Some(filing) = filings.first() {
+///         download_filing(&client, filing, "output").await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn download_filing(client: &Client, filing: &Filing, output_dir: impl AsRef<Path>) -> Result<PathBuf> {
+    let output_dir = output_dir.as_ref();
+    tokio::fs::create_dir_all(output_dir).await.map_err(Error::IoError)?;
+
+    let (url, output_path) = download_target(filing, output_dir);
+    client.download_text(&url, &output_path).await?;
+
+    Ok(output_path)
+}
+
+/// URL and target path [`download_filing`] uses for a filing.
+fn download_target(filing: &Filing, output_dir: &Path) -> (String, PathBuf) {
+    (filing.primary_document_url(), output_dir.join(&filing.primary_document))
+}
+
+/// List the `(url, path)` pairs [`download_all_filings`] would write, without downloading
+///
+/// Applies the same [`filter_by_form`] filter to already-fetched filings,
+/// so a bulk job can be audited or reviewed before it runs. Nothing is
+/// fetched and no directories are created.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::{get_recent_filings, plan_downloads};
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     for (url, path) in plan_downloads(&filings, "10-K", "output/apple-10k") {
+///         println!("{} -> {}", url, path.display());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn plan_downloads(filings: &[Filing], form_type: &str, output_dir: impl AsRef<Path>) -> Vec<(String, PathBuf)> {
+    filter_by_form(filings, form_type)
+        .iter()
+        .map(|filing| download_target(filing, output_dir.as_ref()))
+        .collect()
+}
+
+/// Download the full submission text file (contains all documents)
+///
+/// The submission text file includes all documents in the filing separated
+/// by <DOCUMENT> tags. Useful for getting everything at once.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::{get_recent_filings, download_submission_text};
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     if let Some(filing) = filings.first() {
+///         let path = download_submission_text(&client, filing, "output").await?;
+///         println!("Downloaded to: {:?}", path);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn download_submission_text(
+    client: &Client,
+    filing: &Filing,
+    output_dir: impl AsRef<Path>,
+) -> Result<PathBuf> {
+    let output_dir = output_dir.as_ref();
+    tokio::fs::create_dir_all(output_dir).await.map_err(Error::IoError)?;
+
+    let accession = filing
+        .accession()
+        .map(|acc| acc.dashed())
+        .unwrap_or_else(|_| filing.accession_number.clone());
+    let filename = format!("{}.txt", accession);
+    let output_path = output_dir.join(filename);
+
+    let url = filing.submission_text_url();
+    client.download_text(&url, &output_path).await?;
+
+    Ok(output_path)
+}
+
+/// Filter filings by form type (e.g., "10-K", "10-Q", "8-K")
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::{get_recent_filings, filter_by_form};
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "0000320193").await?;
+///
+///     let ten_ks = filter_by_form(&filings, "10-K");
+///     println!("Found {} 10-K filings", ten_ks.len());
+///     Ok(())
+/// }
+/// ```
+pub fn filter_by_form(filings: &[Filing], form_type: &str) -> Vec<Filing> {
+    filings.iter().filter(|f| f.form_type == form_type).cloned().collect()
+}
+
+/// Get only XBRL filings
+pub fn filter_xbrl(filings: &[Filing]) -> Vec<Filing> {
+    filings.iter().filter(|f| f.is_xbrl).cloned().collect()
+}
+
+/// Download all filings of a specific type for a company
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::download_all_filings;
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///
+///     // Download all 10-K filings for Apple
+///     let paths = download_all_filings(
+///         &client,
+///         "0000320193",
+///         "10-K",
+///         "output/apple-10k"
+///     ).await?;
+///
+///     println!("Downloaded {} filings", paths.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn download_all_filings(
+    client: &Client,
+    cik: &str,
+    form_type: &str,
+    output_dir: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>> {
+    download_all_filings_cancellable(client, cik, form_type, output_dir, CancellationToken::new())
+        .await
+        .map(Cancellable::into_inner)
+}
+
+/// Result of a bulk operation that may have been cancelled part-way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cancellable<T> {
+    /// The operation ran to completion.
+    Completed(T),
+    /// The operation was cancelled; holds the partial result.
+    Cancelled(T),
+}
+
+impl<T> Cancellable<T> {
+    /// Whether the operation was cancelled before completing.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled(_))
+    }
+
+    /// Get the (possibly partial) result.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Completed(value) | Self::Cancelled(value) => value,
+        }
+    }
+
+    /// Transform the result, keeping whether the operation was cancelled.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Cancellable<U> {
+        match self {
+            Self::Completed(value) => Cancellable::Completed(f(value)),
+            Self::Cancelled(value) => Cancellable::Cancelled(f(value)),
+        }
+    }
+}
+
+/// Outcome of a bulk download, filing by filing.
+#[derive(Debug, Default)]
+pub struct DownloadReport {
+    /// Paths of the documents written
+    pub succeeded: Vec<PathBuf>,
+    /// Filings that could not be downloaded, with the reason
+    pub failed: Vec<(Filing, Error)>,
+}
+
+/// Download all filings of a specific type, reporting each failure.
+///
+/// Like [`download_all_filings`], a failed filing does not stop the
+/// others, but instead of being logged the failure is returned in
+/// [`DownloadReport::failed`] so the caller can retry or report it.
+///
+/// # Errors
+///
+/// Returns an error only if the company's filing list cannot be fetched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::download_all_filings_report;
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///
+///     let report = download_all_filings_report(&client, "0000320193", "10-K", "output").await?;
+///     for (filing, error) in &report.failed {
+///         eprintln!("{}: {}", filing.accession_number, error);
+///     }
+///     println!("Downloaded {} filings", report.succeeded.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn download_all_filings_report(
+    client: &Client,
+    cik: &str,
+    form_type: &str,
+    output_dir: impl AsRef<Path>,
+) -> Result<DownloadReport> {
+    download_report(client, cik, form_type, output_dir, CancellationToken::new())
+        .await
+        .map(Cancellable::into_inner)
+}
+
+/// Download all filings of a specific type, stopping early when `token` is cancelled.
+///
+/// The token is checked before each filing, so a download already in
+/// progress is allowed to finish. Paths downloaded before cancellation are
+/// returned inside [`Cancellable::Cancelled`]. Failed filings are skipped
+/// and logged; use [`download_all_filings_report`] to collect them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::download_all_filings_cancellable;
+/// use sec_o3::Client;
+/// use tokio_util::sync::CancellationToken;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let token = CancellationToken::new();
+///
+///     let ctrl_c = token.clone();
+///     tokio::spawn(async move {
+///         tokio::signal::ctrl_c().await.ok();
+///         ctrl_c.cancel();
+///     });
+///
+///     let outcome = download_all_filings_cancellable(&client, "0000320193", "10-K", "output", token).await?;
+///     if outcome.is_cancelled() {
+///         println!("Cancelled after {} filings", outcome.into_inner().len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn download_all_filings_cancellable(
+    client: &Client,
+    cik: &str,
+    form_type: &str,
+    output_dir: impl AsRef<Path>,
+    token: CancellationToken,
+) -> Result<Cancellable<Vec<PathBuf>>> {
+    let outcome = download_report(client, cik, form_type, output_dir, token).await?;
+
+    Ok(outcome.map(|report| {
+        for (filing, e) in &report.failed {
+            tracing::warn!(
+                "Failed to download {} ({}): {}",
+                filing.accession_number,
+                filing.form_type,
+                e
+            );
+        }
+        report.succeeded
+    }))
+}
+
+/// Download filings of `form_type` one at a time until done or cancelled.
+async fn download_report(
+    client: &Client,
+    cik: &str,
+    form_type: &str,
+    output_dir: impl AsRef<Path>,
+    token: CancellationToken,
+) -> Result<Cancellable<DownloadReport>> {
+    let filings = get_recent_filings(client, cik).await?;
+    let filtered = filter_by_form(&filings, form_type);
+
+    let mut report = DownloadReport::default();
+
+    for filing in filtered {
+        if token.is_cancelled() {
+            return Ok(Cancellable::Cancelled(report));
+        }
+
+        match download_filing(client, &filing, &output_dir).await {
+            Ok(path) => report.succeeded.push(path),
+            Err(e) => report.failed.push((filing, e)),
+        }
+    }
+
+    Ok(Cancellable::Completed(report))
+}
+
+/// Download all filings of a specific type for a company within a specified date range
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::filings::download_all_filings;
+/// use sec_o3::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///
+///     // Download all 10-K filings for Apple
+///     let paths = download_all_filings(
+///         &client,
+///         "0000320193",
+///         "10-K",
+///         "output/apple-10k"
+///     ).await?;
+///
+///     println!("Downloaded {} filings", paths.len());
+///     Ok(())
+/// }
+/// ```
+pub async fn download_filings_in_date_range(
+    client: &Client,
+    cik: &str,
+    form_type: &str,
+    _output_dir: impl AsRef<Path>,
+    _start_date: &str,
+    _end_date: &str,
+) -> Result<Vec<PathBuf>> {
+    let filings = get_recent_filings(client, cik).await?;
+    let _filtered = filter_by_form(&filings, form_type);
+
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{mock_client, sample_filing};
+    use crate::utils::str_to_utc_datetime;
+    use serde_json::json;
+    use std::collections::HashSet;
+    use wiremock::matchers::{path, path_regex};
+    use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+    use super::*;
+
+    /// Minimal submissions payload with one recent filing per `(accession, form, document)`.
+    fn submissions_json(cik: &str, filings: &[(&str, &str, &str)]) -> serde_json::Value {
+        json!({
+            "cik": cik,
+            "entityType": "operating",
+            "sic": "3571",
+            "sicDescription": "Electronic Computers",
+            "name": "Test Co",
+            "tickers": ["TEST"],
+            "exchanges": ["Nasdaq"],
+            "filings": {
+                "recent": {
+                    "accessionNumber": filings.iter().map(|f| f.0).collect::<Vec<_>>(),
+                    "form": filings.iter().map(|f| f.1).collect::<Vec<_>>(),
+                    "primaryDocument": filings.iter().map(|f| f.2).collect::<Vec<_>>(),
+                    "acceptanceDateTime": filings.iter().map(|_| "2023-11-03T18:01:14.000Z").collect::<Vec<_>>(),
+                }
+            }
+        })
+    }
+
+    /// Responder that cancels a token as soon as it serves a document.
+    struct CancelOnServe(CancellationToken);
+
+    impl Respond for CancelOnServe {
+        fn respond(&self, _request: &Request) -> ResponseTemplate {
+            self.0.cancel();
+            ResponseTemplate::new(200).set_body_string("<html>FORM 10-K</html>")
+        }
+    }
+
+    #[test]
+    fn test_cancellable_keeps_partial_result() {
+        let completed = Cancellable::Completed(vec![1, 2]);
+        assert!(!completed.is_cancelled());
+        assert_eq!(completed.into_inner(), vec![1, 2]);
+
+        let cancelled = Cancellable::Cancelled(vec![1]);
+        assert!(cancelled.is_cancelled());
+        assert_eq!(cancelled.into_inner(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_get_submissions() {
+        let client = Client::new("TestApp", "test@example.com");
+        let result = get_submissions(&client, "0000320193").await;
+        assert!(
+            result.is_ok(),
+            "Something happened while getting submissions {:?}",
+            result.err()
+        );
+
+        let submissions = result.unwrap();
+        assert_eq!(submissions.cik, "0000320193");
+        assert!(submissions.name.contains("Apple"));
+    }
+
+    #[tokio::test]
+    async fn test_get_recent_filings() {
+        let client = Client::new("TestApp", "test@example.com");
+        let result = get_recent_filings(&client, "0000320193").await;
+        assert!(
+            result.is_ok(),
+            "Something happened while getting recent filings {:?}",
+            result.err()
+        );
+
+        let filings = result.unwrap();
+        assert!(!filings.is_empty());
+    }
+
+    #[test]
+    fn test_filing_urls() {
+        let filing = Filing {
+            cik: "320193".to_string(),
+            accession_number: "0000320193-23-000106".to_string(),
+            form_type: "10-K".to_string(),
+            acceptance_date: str_to_utc_datetime("2023-11-03T00:00:00.000Z")
+                .expect("An invalid UTC date was provided as an acceptance date."),
+            primary_document: "aapl-20230930.htm".to_string(),
+            is_xbrl: true,
+            size: None,
+            report_date: None,
+        };
+
+        assert_eq!(
+            filing.base_url(),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/"
+        );
+
+        assert_eq!(
+            filing.primary_document_url(),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm"
+        );
+
+        let undashed = Filing {
+            accession_number: "000032019323000106".to_string(),
+            ..filing.clone()
+        };
+        assert_eq!(undashed.base_url(), filing.base_url());
+        assert_eq!(undashed.submission_text_url(), filing.submission_text_url());
+        assert_eq!(undashed.index_url(), filing.index_url());
+        assert_eq!(undashed.accession().unwrap(), filing.accession().unwrap());
+    }
+
+    #[test]
+    fn test_public_url() {
+        let filing = Filing {
+            cik: "0000320193".to_string(),
+            accession_number: "000032019323000106".to_string(),
+            ..sample_filing()
+        };
+
+        assert_eq!(
+            filing.public_url(),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106-index.htm"
+        );
+        assert_ne!(filing.public_url(), filing.base_url());
+    }
+
+    #[test]
+    fn test_filter_by_form() {
+        let filings = vec![
+            Filing {
+                cik: "123".to_string(),
+                accession_number: "0001-23-001".to_string(),
+                form_type: "10-K".to_string(),
+                acceptance_date: str_to_utc_datetime("2023-01-01T00:00:00.000Z")
+                    .expect("An invalid UTC datetime was provided as an acceptance date."),
+                primary_document: "doc.xml".to_string(),
+                is_xbrl: true,
+                size: None,
+                report_date: None,
+            },
+            Filing {
+                cik: "123".to_string(),
+                accession_number: "0001-23-002".to_string(),
+                form_type: "10-Q".to_string(),
+                acceptance_date: str_to_utc_datetime("2023-04-01T00:00:00.000Z")
+                    .expect("An invalid UTC datetime was provided as an acceptance date."),
+                primary_document: "doc2.xml".to_string(),
+                is_xbrl: true,
+                size: None,
+                report_date: None,
+            },
+        ];
+
+        let ten_ks = filter_by_form(&filings, "10-K");
+        assert_eq!(ten_ks.len(), 1);
+        assert_eq!(ten_ks[0].form_type, "10-K");
+    }
+
+    #[test]
+    fn test_reported_and_total_sizes() {
+        let mut payload = submissions_json(
+            "0000000123",
+            &[
+                ("0000000123-23-000003", "10-K", "a.htm"),
+                ("0000000123-23-000002", "8-K", "b.htm"),
+                ("0000000123-23-000001", "10-Q", "c.htm"),
+            ],
+        );
+        payload["filings"]["recent"]["size"] = json!([1_500_000, 20_000, 750_000]);
+
+        let submissions: Submissions = serde_json::from_value(payload).unwrap();
+        assert_eq!(submissions.total_recent_size(), 2_270_000);
+
+        let filings = submissions.recent_filings();
+        let sizes: Vec<Option<u64>> = filings.iter().map(Filing::reported_size).collect();
+        assert_eq!(sizes, vec![Some(1_500_000), Some(20_000), Some(750_000)]);
+    }
+
+    #[test]
+    fn test_distinct_forms() {
+        let submissions: Submissions = serde_json::from_value(submissions_json(
+            "0000000123",
+            &[
+                ("0000000123-23-000006", "8-K", "a.htm"),
+                ("0000000123-23-000005", "10-Q", "b.htm"),
+                ("0000000123-23-000004", "8-K", "c.htm"),
+                ("0000000123-23-000003", "4", "d.xml"),
+                ("0000000123-23-000002", "10-Q", "e.htm"),
+                ("0000000123-23-000001", "8-K", "f.htm"),
+                ("0000000123-22-000001", "10-K", "g.htm"),
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            submissions.distinct_forms(),
+            vec![
+                ("8-K".to_string(), 3),
+                ("10-Q".to_string(), 2),
+                ("10-K".to_string(), 1),
+                ("4".to_string(), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_all_filings_cancellable_stops_between_filings() {
+        let server = MockServer::start().await;
+        let token = CancellationToken::new();
+
+        Mock::given(path("/submissions/CIK0000000123.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(submissions_json(
+                "0000000123",
+                &[
+                    ("0000000123-23-000002", "10-K", "test-2023.htm"),
+                    ("0000000123-22-000001", "10-K", "test-2022.htm"),
+                ],
+            )))
+            .mount(&server)
+            .await;
+
+        Mock::given(path_regex(r"^/Archives/edgar/data/"))
+            .respond_with(CancelOnServe(token.clone()))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+
+        let outcome = download_all_filings_cancellable(&client, "123", "10-K", dir.path(), token)
+            .await
+            .unwrap();
+
+        assert!(outcome.is_cancelled());
+        assert_eq!(outcome.into_inner(), vec![dir.path().join("test-2023.htm")]);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_download_all_filings_report_collects_failures() {
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000000123.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(submissions_json(
+                "0000000123",
+                &[
+                    ("0000000123-23-000002", "10-K", "test-2023.htm"),
+                    ("0000000123-22-000001", "10-K", "missing-2022.htm"),
+                ],
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(path("/Archives/edgar/data/123/000000012323000002/test-2023.htm"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>FORM 10-K</html>"))
+            .mount(&server)
+            .await;
+        Mock::given(path("/Archives/edgar/data/123/000000012322000001/missing-2022.htm"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let dir = tempfile::tempdir().unwrap();
+
+        let report = download_all_filings_report(&client, "123", "10-K", dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(report.succeeded, vec![dir.path().join("test-2023.htm")]);
+        assert_eq!(report.failed.len(), 1);
+        let (filing, error) = &report.failed[0];
+        assert_eq!(filing.accession_number, "0000000123-22-000001");
+        assert!(matches!(error, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn test_plan_downloads_matches_filings() {
+        let submissions: Submissions = serde_json::from_value(submissions_json(
+            "0000000123",
+            &[
+                ("0000000123-23-000002", "10-K", "test-2023.htm"),
+                ("0000000123-23-000001", "8-K", "test-8k.htm"),
+                ("0000000123-22-000001", "10-K", "test-2022.htm"),
+            ],
+        ))
+        .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let output_dir = dir.path().join("plan");
+
+        let plan = plan_downloads(&submissions.recent_filings(), "10-K", &output_dir);
+
+        assert_eq!(
+            plan,
+            vec![
+                (
+                    "https://www.sec.gov/Archives/edgar/data/123/000000012323000002/test-2023.htm".to_string(),
+                    output_dir.join("test-2023.htm"),
+                ),
+                (
+                    "https://www.sec.gov/Archives/edgar/data/123/000000012322000001/test-2022.htm".to_string(),
+                    output_dir.join("test-2022.htm"),
+                ),
+            ]
+        );
+        assert!(!output_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn test_best_document_url_falls_back_to_index() {
+        let server = MockServer::start().await;
+        Mock::given(path("/Archives/edgar/data/1234/000123409000000001/index.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "directory": {
+                    "name": "/Archives/edgar/data/1234/000123409000000001",
+                    "item": [
+                        { "name": "0001234090-00-000001-index.htm", "type": "text.gif", "size": "" },
+                        { "name": "0001234090-00-000001.txt", "type": "text.gif", "size": "900000" },
+                        { "name": "ex99.htm", "type": "text.gif", "size": "12000" },
+                        { "name": "form10k.htm", "type": "text.gif", "size": "450000" }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let mut filing = Filing {
+            cik: "1234".to_string(),
+            accession_number: "0001234090-00-000001".to_string(),
+            form_type: "10-K".to_string(),
+            acceptance_date: str_to_utc_datetime("2000-03-30").unwrap(),
+            primary_document: String::new(),
+            is_xbrl: false,
+            size: None,
+            report_date: None,
+        };
+
+        assert_eq!(
+            filing.best_document_url(&client).await.unwrap(),
+            "https://www.sec.gov/Archives/edgar/data/1234/000123409000000001/form10k.htm"
+        );
+
+        filing.primary_document = "main.htm".to_string();
+        assert_eq!(
+            filing.best_document_url(&client).await.unwrap(),
+            "https://www.sec.gov/Archives/edgar/data/1234/000123409000000001/main.htm"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_submissions_html_error_page_is_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK9999999999.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "text/html")
+                    .set_body_string(
+                        "<!DOCTYPE html>\n<html><head><title>EDGAR Search Results</title></head>\n\
+                 <body><center><h1>No matching CIK.</h1>\n\
+                 <p>Please check the CIK and try again.</p></center></body></html>",
+                    ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+
+        let result = get_submissions(&client, "9999999999").await;
+        assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_filing_arrays_are_rejected() {
+        let mut body = submissions_json(
+            "0000001234",
+            &[
+                ("0001234090-23-000001", "10-K", "form10k.htm"),
+                ("0001234090-23-000002", "8-K", "form8k.htm"),
+            ],
+        );
+        body["filings"]["recent"]["form"] = json!(["10-K"]);
+
+        let recent: RecentFilings = serde_json::from_value(body["filings"]["recent"].clone()).unwrap();
+        let err = recent.validate_lengths().unwrap_err();
+        assert!(err.to_string().contains("`form` has 1 entries"), "{}", err);
+
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000001234.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        assert!(matches!(get_submissions(&client, "1234").await, Err(Error::Custom(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_submissions_rejects_cik_mismatch() {
+        let body = submissions_json("789019", &[("0000789019-23-000001", "10-K", "msft-10k.htm")]);
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000320193.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let err = get_submissions(&client, "320193").await.unwrap_err();
+        assert!(matches!(err, Error::Custom(_)));
+        assert!(err.to_string().contains("CIK mismatch"), "{}", err);
+
+        assert!(check_cik_matches("320193", "CIK0000320193").is_ok());
+        assert!(check_cik_matches("", "not a cik").is_err());
+        assert!(check_cik_matches("abc", "320193").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_former_names() {
+        let mut body = submissions_json("0000320193", &[]);
+        body["formerNames"] = json!([
+            { "name": "APPLE COMPUTER INC", "from": "1994-01-26T05:00:00.000Z", "to": "2007-01-04T05:00:00.000Z" },
+            { "name": "APPLE INC/CA", "from": "2007-01-04", "to": "2007-01-10" }
+        ]);
+
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+
+        assert_eq!(submissions.former_names.len(), 2);
+        assert_eq!(submissions.former_names[0].name, "APPLE COMPUTER INC");
+        assert_eq!(submissions.former_names[0].to, NaiveDate::from_ymd_opt(2007, 1, 4));
+        assert_eq!(submissions.former_names[1].from, NaiveDate::from_ymd_opt(2007, 1, 4));
+        assert_eq!(submissions.redirected_from, None);
+
+        body = submissions_json("0000320193", &[]);
+        body["formerNames"] = json!([{ "name": "APPLE INC/CA", "from": "2007-01-04", "to": "soon" }]);
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+        assert_eq!(submissions.former_names[0].to, None);
+    }
+
+    #[test]
+    fn test_former_name_null_date() {
+        let mut body = submissions_json("0000320193", &[]);
+        body["name"] = json!("Apple Inc.");
+        body["formerNames"] = json!([
+            { "name": "APPLE COMPUTER INC", "from": "1994-01-26T05:00:00.000Z", "to": null },
+            { "name": "APPLE INC/CA", "from": "2007-01-04", "to": "2007-01-10" },
+            { "name": "APPLE COMPUTER INC/OLD", "to": "1990-01-01" }
+        ]);
+
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(submissions.former_names[0].to, None);
+        assert_eq!(submissions.former_names[2].from, None);
+        // The entry without a `to` date runs until the next name's `from`
+        assert_eq!(submissions.name_at(date(2000, 6, 30)), "APPLE COMPUTER INC");
+        assert_eq!(submissions.name_at(date(2007, 1, 4)), "APPLE COMPUTER INC");
+        assert_eq!(submissions.name_at(date(2007, 1, 5)), "APPLE INC/CA");
+        assert_eq!(submissions.name_at(date(2008, 1, 1)), "Apple Inc.");
+    }
+
+    #[test]
+    fn test_former_name_empty_date() {
+        let mut body = submissions_json("0000320193", &[]);
+        body["name"] = json!("Apple Inc.");
+        body["formerNames"] = json!([{ "name": "APPLE COMPUTER INC", "from": "", "to": "" }]);
+
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+
+        assert_eq!(submissions.former_names[0].from, None);
+        assert_eq!(submissions.former_names[0].to, None);
+        let date = NaiveDate::from_ymd_opt(2000, 6, 30).unwrap();
+        assert_eq!(submissions.name_at(date), "Apple Inc.");
+    }
+
+    #[test]
+    fn test_name_at() {
+        let mut body = submissions_json("0000320193", &[]);
+        body["name"] = json!("Apple Inc.");
+        body["formerNames"] = json!([
+            { "name": "APPLE COMPUTER INC", "from": "1994-01-26T05:00:00.000Z", "to": "2007-01-04T05:00:00.000Z" }
+        ]);
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(submissions.name_at(date(2000, 6, 30)), "APPLE COMPUTER INC");
+        assert_eq!(submissions.name_at(date(2007, 1, 4)), "APPLE COMPUTER INC");
+        assert_eq!(submissions.name_at(date(2007, 1, 5)), "Apple Inc.");
+        assert_eq!(submissions.name_at(date(1990, 1, 1)), "APPLE COMPUTER INC");
+    }
+
+    #[test]
+    fn test_period_label_september_year_end() {
+        let mut body = submissions_json(
+            "0000320193",
+            &[
+                ("0000320193-23-000077", "10-Q", "aapl-20230701.htm"),
+                ("0000320193-23-000106", "10-K", "aapl-20230930.htm"),
+                ("0000320193-24-000006", "10-Q", "aapl-20231230.htm"),
+                ("0000320193-23-000070", "8-K", "aapl-20230613.htm"),
+            ],
+        );
+        body["filings"]["recent"]["reportDate"] = json!(["2023-07-01", "2023-09-30", "2023-12-30", ""]);
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+        let labels: Vec<Option<String>> = submissions
+            .recent_filings()
+            .iter()
+            .map(|filing| filing.period_label(9))
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                Some("Q3 FY2023".to_string()),
+                Some("FY2023".to_string()),
+                Some("Q1 FY2024".to_string()),
+                None,
+            ]
+        );
+        assert_eq!(submissions.recent_filings()[0].period_label(13), None);
+    }
+
+    #[test]
+    fn test_new_accessions() {
+        let body = submissions_json(
+            "0000320193",
+            &[
+                ("0000320193-24-000003", "8-K", "c.htm"),
+                ("0000320193-24-000002", "10-Q", "b.htm"),
+                ("0000320193-24-000001", "8-K", "a.htm"),
+                ("0000320193-23-000106", "10-K", "aapl-20230930.htm"),
+            ],
+        );
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+        let seen: HashSet<String> = ["0000320193-24-000002", "0000320193-23-000106", "0000320193-22-000108"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let new = submissions.filings.recent.new_accessions(&submissions.cik, &seen);
+
+        let accessions: Vec<&str> = new.iter().map(|f| f.accession_number.as_str()).collect();
+        assert_eq!(accessions, ["0000320193-24-000003", "0000320193-24-000001"]);
+
+        // A cursor stored undashed, as Filing::filing_id writes it
+        let undashed: HashSet<String> = seen.iter().map(|accession| accession.replace('-', "")).collect();
+        let new = submissions.filings.recent.new_accessions(&submissions.cik, &undashed);
+        let accessions: Vec<&str> = new.iter().map(|f| f.accession_number.as_str()).collect();
+        assert_eq!(accessions, ["0000320193-24-000003", "0000320193-24-000001"]);
+
+        let all = submissions
+            .filings
+            .recent
+            .new_accessions(&submissions.cik, &HashSet::new());
+        assert_eq!(all.len(), 4);
+    }
+
+    #[test]
+    fn test_likely_has_xbrl_facts() {
+        let mut company = submissions_json("0000320193", &[("0000320193-23-000106", "10-K", "aapl-20230930.htm")]);
+        company["filings"]["recent"]["isXBRL"] = json!([1]);
+        company["filings"]["recent"]["isInlineXBRL"] = json!([1]);
+        let company: Submissions = serde_json::from_value(company).unwrap();
+        assert!(company.likely_has_xbrl_facts());
+
+        let mut person = submissions_json("0001214156", &[("0001209191-23-058813", "4", "xslF345X05/doc4.xml")]);
+        person["entityType"] = json!("individual");
+        person["filings"]["recent"]["isXBRL"] = json!([0]);
+        person["filings"]["recent"]["isInlineXBRL"] = json!([0]);
+        let person: Submissions = serde_json::from_value(person).unwrap();
+        assert!(!person.likely_has_xbrl_facts());
+
+        let mut fund = submissions_json("0001100663", &[("0001752724-23-250000", "N-PORT-P", "primary_doc.xml")]);
+        fund["entityType"] = json!("other");
+        let fund: Submissions = serde_json::from_value(fund).unwrap();
+        assert!(!fund.likely_has_xbrl_facts());
+    }
+
+    #[tokio::test]
+    async fn test_get_submissions_follows_redirect_to_successor() {
+        let server = MockServer::start().await;
+        Mock::given(path("/submissions/CIK0000000111.json"))
+            .respond_with(
+                ResponseTemplate::new(301)
+                    .insert_header("Location", "https://data.sec.gov/submissions/CIK0000000222.json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/submissions/CIK0000000222.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(submissions_json("0000000222", &[])))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let submissions = get_submissions(&client, "111").await.unwrap();
+
+        assert_eq!(submissions.cik, "0000000222");
+        assert_eq!(submissions.redirected_from.as_deref(), Some("111"));
+
+        assert_eq!(
+            successor_cik("/submissions/CIK0000000222.json?x=1").as_deref(),
+            Some("0000000222")
+        );
+        assert_eq!(successor_cik("https://www.sec.gov/edgar/search/"), None);
+    }
+
+    #[test]
+    fn test_fiscal_year_end_month() {
+        let mut body = submissions_json("0000320193", &[]);
+        body["fiscalYearEnd"] = json!("0930");
+        let submissions: Submissions = serde_json::from_value(body.clone()).unwrap();
+        assert_eq!(submissions.fiscal_year_end.as_deref(), Some("0930"));
+        assert_eq!(submissions.fiscal_year_end_month(), Some(9));
+
+        for malformed in [json!(null), json!(""), json!("1331"), json!("930")] {
+            body["fiscalYearEnd"] = malformed;
+            let submissions: Submissions = serde_json::from_value(body.clone()).unwrap();
+            assert_eq!(submissions.fiscal_year_end_month(), None);
+        }
+
+        let submissions: Submissions = serde_json::from_value(submissions_json("0000320193", &[])).unwrap();
+        assert_eq!(submissions.fiscal_year_end, None);
+    }
+
+    #[test]
+    fn test_filings_by_year() {
+        let mut body = submissions_json(
+            "0000000123",
+            &[
+                ("0000000123-24-000002", "8-K", "a.htm"),
+                ("0000000123-23-000003", "10-K", "b.htm"),
+                ("0000000123-23-000001", "10-Q", "c.htm"),
+                ("0000000123-23-000002", "8-K", "d.htm"),
+                ("0000000123-22-000001", "10-K", "e.htm"),
+            ],
+        );
+        body["filings"]["recent"]["acceptanceDateTime"] = json!([
+            "2024-02-01T16:00:00.000Z",
+            "2023-11-03T18:01:14.000Z",
+            "2023-02-03T18:01:14.000Z",
+            "2023-08-04T18:01:14.000Z",
+            "2022-10-28T18:01:14.000Z",
+        ]);
+        let submissions: Submissions = serde_json::from_value(body).unwrap();
+
+        let by_year = submissions.filings_by_year();
+
+        assert_eq!(by_year.keys().copied().collect::<Vec<_>>(), vec![2022, 2023, 2024]);
+        let accessions_2023: Vec<&str> = by_year[&2023].iter().map(|f| f.accession_number.as_str()).collect();
+        assert_eq!(
+            accessions_2023,
+            vec!["0000000123-23-000003", "0000000123-23-000002", "0000000123-23-000001"]
+        );
+        assert_eq!(by_year[&2022].len(), 1);
+        assert_eq!(by_year[&2024].len(), 1);
+    }
+
+    #[test]
+    fn test_filing_identity_ignores_formatting() {
+        let dashed = sample_filing();
+        let undashed = Filing {
+            cik: "0000320193".to_string(),
+            accession_number: "000032019323000106".to_string(),
+            primary_document: String::new(),
+            ..dashed.clone()
+        };
+        let other = Filing {
+            accession_number: "0000320193-23-000077".to_string(),
+            ..dashed.clone()
+        };
+
+        assert_eq!(dashed, undashed);
+        assert_ne!(dashed, other);
+
+        let unique: HashSet<Filing> = [dashed, undashed].into_iter().collect();
+        assert_eq!(unique.len(), 1);
+    }
+
+    #[test]
+    fn test_filing_age_and_is_recent() {
+        let filing = sample_filing();
+        let as_of = str_to_utc_datetime("2023-11-05T18:01:14.000Z").unwrap();
+
+        assert_eq!(filing.age(as_of), Duration::days(2));
+        assert!(filing.is_recent(as_of, Duration::days(7)));
+        assert!(!filing.is_recent(as_of, Duration::days(1)));
+        assert!(!filing.is_recent(as_of - Duration::days(3), Duration::days(7)));
+    }
+
+    #[test]
+    fn test_xbrl_flags_use_sec_field_names() {
+        // Excerpt of https://data.sec.gov/submissions/CIK0000320193.json
+        let recent: RecentFilings = serde_json::from_str(
+            r#"{
+                "accessionNumber": ["0000320193-23-000106", "0001140361-23-049869"],
+                "filingDate": ["2023-11-03", "2023-10-27"],
+                "reportDate": ["2023-09-30", ""],
+                "acceptanceDateTime": ["2023-11-02T18:08:27.000Z", "2023-10-27T18:30:13.000Z"],
+                "act": ["34", ""],
+                "form": ["10-K", "4"],
+                "fileNumber": ["001-36743", ""],
+                "filmNumber": ["231373899", ""],
+                "items": ["", ""],
+                "size": [9658123, 4754],
+                "isXBRL": [1, 0],
+                "isInlineXBRL": [1, 0],
+                "primaryDocument": ["aapl-20230930.htm", "xslF345X05/form4.xml"],
+                "primaryDocDescription": ["10-K", "FORM 4"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(recent.is_xbrl, vec![1, 0]);
+        assert_eq!(recent.is_inline_xbrl, vec![1, 0]);
+
+        let filings = recent.to_filings("0000320193");
+        assert!(filings[0].is_xbrl);
+        assert!(!filings[1].is_xbrl);
+    }
+
+    #[test]
+    fn test_filing_id_is_normalized() {
+        let filing = sample_filing();
+        let undashed = Filing {
+            cik: "0000320193".to_string(),
+            accession_number: "000032019323000106".to_string(),
+            ..filing.clone()
+        };
+
+        assert_eq!(filing.filing_id(), "0000320193-000032019323000106");
+        assert_eq!(undashed.filing_id(), filing.filing_id());
+    }
+}
@@ -0,0 +1,174 @@
+//! Periodic filing schedules.
+//!
+//! Periodic reports are due a fixed number of days after the end of the
+//! reporting period. The deadlines used here are those for non-accelerated
+//! filers (45 days for 10-Q, 90 days for 10-K), the latest any domestic
+//! filer may file without an extension.
+use chrono::{Datelike, Days, Months, NaiveDate, TimeDelta};
+
+/// Days after quarter end that a 10-Q is due (non-accelerated filers).
+pub const QUARTERLY_DEADLINE_DAYS: u64 = 45;
+
+/// Days after fiscal year end that a 10-K is due (non-accelerated filers).
+pub const ANNUAL_DEADLINE_DAYS: u64 = 90;
+
+/// How often a company files a given kind of report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilingFrequency {
+    /// Once per fiscal year (e.g., 10-K, 20-F)
+    Annual,
+    /// Once per fiscal quarter (e.g., 10-Q)
+    Quarterly,
+    /// Triggered by corporate events (e.g., 8-K)
+    EventDriven,
+    /// No regular schedule (e.g., S-1, proxy statements)
+    AsNeeded,
+}
+
+/// Estimate when the next periodic filing is due.
+///
+/// Advances `last_report` (the end of the last reported period) by one
+/// quarter or year and adds the SEC filing deadline. Month-end periods stay
+/// at month end (e.g., Sep 30 → Dec 31). Returns `None` for
+/// [`FilingFrequency::EventDriven`] and [`FilingFrequency::AsNeeded`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sec_o3::filings::{next_expected_filing, FilingFrequency};
+///
+/// let last = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
+/// let next = next_expected_filing(last, FilingFrequency::Quarterly);
+/// assert_eq!(next, NaiveDate::from_ymd_opt(2024, 2, 14));
+/// ```
+pub fn next_expected_filing(last_report: NaiveDate, freq: FilingFrequency) -> Option<NaiveDate> {
+    let (months, deadline_days) = match freq {
+        FilingFrequency::Quarterly => (3, QUARTERLY_DEADLINE_DAYS),
+        FilingFrequency::Annual => (12, ANNUAL_DEADLINE_DAYS),
+        FilingFrequency::EventDriven | FilingFrequency::AsNeeded => return None,
+    };
+
+    let next_period_end = add_months_keep_month_end(last_report, months)?;
+    next_period_end.checked_add_days(Days::new(deadline_days))
+}
+
+/// Whether the next periodic filing is overdue as of `as_of`.
+///
+/// A filing is overdue once `as_of` is more than `grace_days` past the date
+/// returned by [`next_expected_filing`]. Always `false` for
+/// [`FilingFrequency::EventDriven`] and [`FilingFrequency::AsNeeded`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use sec_o3::filings::{is_overdue, FilingFrequency};
+///
+/// let last = NaiveDate::from_ymd_opt(2023, 9, 30).unwrap();
+/// let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+/// assert!(is_overdue(last, FilingFrequency::Quarterly, today, 5));
+/// ```
+pub fn is_overdue(last_report: NaiveDate, freq: FilingFrequency, as_of: NaiveDate, grace_days: i64) -> bool {
+    next_expected_filing(last_report, freq)
+        .and_then(|due| due.checked_add_signed(TimeDelta::try_days(grace_days)?))
+        .is_some_and(|deadline| as_of > deadline)
+}
+
+/// Add months to a date, keeping month-end dates at month end.
+fn add_months_keep_month_end(date: NaiveDate, months: u32) -> Option<NaiveDate> {
+    let shifted = date.checked_add_months(Months::new(months))?;
+
+    if is_month_end(date) {
+        last_day_of_month(shifted.year(), shifted.month())
+    } else {
+        Some(shifted)
+    }
+}
+
+fn is_month_end(date: NaiveDate) -> bool {
+    date.succ_opt().is_some_and(|next| next.month() != date.month())
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }?;
+    first_of_next.pred_opt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_next_expected_annual() {
+        assert_eq!(
+            next_expected_filing(date(2023, 9, 30), FilingFrequency::Annual),
+            Some(date(2024, 12, 29))
+        );
+        assert_eq!(
+            next_expected_filing(date(2022, 12, 31), FilingFrequency::Annual),
+            Some(date(2024, 3, 30))
+        );
+    }
+
+    #[test]
+    fn test_next_expected_quarterly() {
+        assert_eq!(
+            next_expected_filing(date(2023, 9, 30), FilingFrequency::Quarterly),
+            Some(date(2024, 2, 14))
+        );
+        // Month-end periods snap to month end, including leap February
+        assert_eq!(
+            next_expected_filing(date(2023, 11, 30), FilingFrequency::Quarterly),
+            Some(date(2024, 4, 14))
+        );
+        // 52/53-week fiscal quarters ending mid-month are shifted as-is
+        assert_eq!(
+            next_expected_filing(date(2023, 7, 1), FilingFrequency::Quarterly),
+            Some(date(2023, 11, 15))
+        );
+    }
+
+    #[test]
+    fn test_next_expected_non_periodic() {
+        assert_eq!(
+            next_expected_filing(date(2023, 9, 30), FilingFrequency::EventDriven),
+            None
+        );
+        assert_eq!(next_expected_filing(date(2023, 9, 30), FilingFrequency::AsNeeded), None);
+    }
+
+    #[test]
+    fn test_is_overdue_quarterly() {
+        let last = date(2023, 9, 30);
+
+        // Due 2024-02-14
+        assert!(!is_overdue(last, FilingFrequency::Quarterly, date(2024, 2, 1), 0));
+        assert!(!is_overdue(last, FilingFrequency::Quarterly, date(2024, 2, 14), 0));
+        assert!(is_overdue(last, FilingFrequency::Quarterly, date(2024, 2, 15), 0));
+
+        // Within the grace period
+        assert!(!is_overdue(last, FilingFrequency::Quarterly, date(2024, 2, 20), 10));
+        assert!(is_overdue(last, FilingFrequency::Quarterly, date(2024, 2, 25), 10));
+    }
+
+    #[test]
+    fn test_is_overdue_non_periodic() {
+        let far_future = date(2030, 1, 1);
+        assert!(!is_overdue(
+            date(2023, 9, 30),
+            FilingFrequency::EventDriven,
+            far_future,
+            0
+        ));
+        assert!(!is_overdue(date(2023, 9, 30), FilingFrequency::AsNeeded, far_future, 0));
+    }
+}
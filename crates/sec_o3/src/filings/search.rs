@@ -0,0 +1,203 @@
+//! EDGAR company name search.
+//!
+//! `browse-edgar?action=getcompany&company=...` lists the companies whose
+//! names start with the query, as an Atom feed. It resolves names to CIKs
+//! online, without downloading the full company list.
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::current::local_name;
+use crate::parse::resolve_xml_entity;
+use crate::utils::build_company_search_url;
+use crate::utils::cik::fetch_tickers_by_cik;
+use crate::{Client, Error, Result};
+
+/// A company matching a name search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyMatch {
+    /// Company's 10-digit Central Index Key
+    pub cik: String,
+    /// Company name as registered with the SEC
+    pub name: String,
+    /// Ticker symbol from the SEC ticker file, if the company has one
+    pub ticker: Option<String>,
+}
+
+/// Search EDGAR for companies whose names start with `name`.
+///
+/// Returns an empty list when nothing matches. The search results carry
+/// no tickers, so when there are matches the SEC ticker file is fetched
+/// and each company's ticker filled in from it. Tickers are left empty if
+/// that file cannot be fetched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::search_companies;
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     for company in search_companies(&client, "Apple").await? {
+///         println!("{} {}", company.cik, company.name);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn search_companies(client: &Client, name: &str) -> Result<Vec<CompanyMatch>> {
+    let body = client.get_bytes(&build_company_search_url(name)).await?;
+    let mut matches = parse_company_search(&body)?;
+
+    if !matches.is_empty() {
+        if let Ok(mut tickers) = fetch_tickers_by_cik(client).await {
+            for company in &mut matches {
+                company.ticker = tickers.remove(&company.cik);
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Parse a browse-edgar company search Atom feed.
+///
+/// A search with several matches lists one `<company-info>` per entry. A
+/// search with exactly one match returns that company's filing feed
+/// instead, whose single `<company-info>` names it as `<conformed-name>`;
+/// both shapes are handled. Entries without a CIK are skipped. The feed
+/// has no tickers, so [`CompanyMatch::ticker`] is always `None` here.
+///
+/// # Errors
+///
+/// Returns `Error::XmlError` if the feed is not well-formed XML.
+pub fn parse_company_search(xml: &[u8]) -> Result<Vec<CompanyMatch>> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buf = Vec::new();
+
+    let mut matches = Vec::new();
+    let mut company: Option<CompanyMatch> = None;
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(start)) => {
+                if local_name(&start) == "company-info" {
+                    company = Some(CompanyMatch {
+                        cik: String::new(),
+                        name: String::new(),
+                        ticker: None,
+                    });
+                }
+                text.clear();
+            }
+            Ok(Event::Text(t)) => text.push_str(&t.decode().map_err(|e| Error::XmlError(e.to_string()))?),
+            Ok(Event::GeneralRef(entity)) => text.push_str(&resolve_xml_entity(&entity)?),
+            Ok(Event::End(end)) => {
+                let name = String::from_utf8_lossy(end.local_name().as_ref()).into_owned();
+                if name == "company-info" {
+                    matches.extend(company.take().filter(|company| !company.cik.is_empty()));
+                } else if let Some(company) = company.as_mut() {
+                    let value = text.trim().to_string();
+                    match name.as_str() {
+                        "cik" => company.cik = format!("{:0>10}", value),
+                        "name" | "conformed-name" => company.name = value,
+                        _ => {}
+                    }
+                }
+                text.clear();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlError(e.to_string())),
+        }
+        buf.clear();
+    }
+
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::retry::RetryPolicy;
+    use crate::test_utils::mock_client;
+    use wiremock::matchers::{path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SEARCH: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/company_search.atom"));
+
+    #[tokio::test]
+    async fn test_search_companies_parses_captured_response() {
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .and(query_param("action", "getcompany"))
+            .and(query_param("company", "apple"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(SEARCH))
+            .mount(&server)
+            .await;
+        Mock::given(path("/include/ticker.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("aapl\t320193\nmsft\t789019\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let matches = search_companies(&client, "apple").await.unwrap();
+
+        assert_eq!(matches.len(), 3);
+        assert_eq!(
+            matches[0],
+            CompanyMatch {
+                cik: "0000320193".to_string(),
+                name: "Apple Inc.".to_string(),
+                ticker: Some("AAPL".to_string()),
+            }
+        );
+        assert_eq!(matches[2].name, "APPLE SMITH & CO");
+        assert_eq!(matches[2].ticker, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_companies_without_ticker_file() {
+        let server = MockServer::start().await;
+        Mock::given(path("/cgi-bin/browse-edgar"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(SEARCH))
+            .mount(&server)
+            .await;
+        let client = Client::builder()
+            .user_agent("TestApp test@example.com")
+            .base_url(server.uri())
+            .retry(RetryPolicy::new(1))
+            .build()
+            .unwrap();
+
+        let matches = search_companies(&client, "apple").await.unwrap();
+
+        assert_eq!(matches.len(), 3);
+        assert!(matches.iter().all(|company| company.ticker.is_none()));
+    }
+
+    #[test]
+    fn test_parse_single_match_company_feed() {
+        let feed = r#"<?xml version="1.0" encoding="ISO-8859-1" ?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+<company-info>
+<addresses><address type="business"><city>CUPERTINO</city></address></addresses>
+<cik>0000320193</cik>
+<conformed-name>Apple Inc.</conformed-name>
+<state-location>CA</state-location>
+</company-info>
+<entry><title>10-K</title></entry>
+</feed>"#;
+
+        let matches = parse_company_search(feed.as_bytes()).unwrap();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].cik, "0000320193");
+        assert_eq!(matches[0].name, "Apple Inc.");
+        assert!(parse_company_search(b"<feed><title>No matches</title></feed>")
+            .unwrap()
+            .is_empty());
+    }
+}
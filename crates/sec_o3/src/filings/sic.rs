@@ -0,0 +1,149 @@
+//! Standard Industrial Classification (SIC) lookups.
+//!
+//! The SEC assigns every registrant a four-digit SIC code (see
+//! [`Submissions::sic`](super::Submissions::sic)). Codes roll up into
+//! ten divisions (A–J) defined by ranges of the first digits.
+use std::fmt;
+
+/// SIC division, the broadest level of the SIC hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SicDivision {
+    /// Division A: 0100–0999
+    Agriculture,
+    /// Division B: 1000–1499
+    Mining,
+    /// Division C: 1500–1799
+    Construction,
+    /// Division D: 2000–3999
+    Manufacturing,
+    /// Division E: 4000–4999
+    TransportationUtilities,
+    /// Division F: 5000–5199
+    WholesaleTrade,
+    /// Division G: 5200–5999
+    RetailTrade,
+    /// Division H: 6000–6799
+    FinanceInsuranceRealEstate,
+    /// Division I: 7000–8999
+    Services,
+    /// Division J: 9100–9729
+    PublicAdministration,
+}
+
+impl SicDivision {
+    /// Division letter (A–J).
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Agriculture => 'A',
+            Self::Mining => 'B',
+            Self::Construction => 'C',
+            Self::Manufacturing => 'D',
+            Self::TransportationUtilities => 'E',
+            Self::WholesaleTrade => 'F',
+            Self::RetailTrade => 'G',
+            Self::FinanceInsuranceRealEstate => 'H',
+            Self::Services => 'I',
+            Self::PublicAdministration => 'J',
+        }
+    }
+
+    /// Human-readable sector label for the division.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Agriculture => "Agriculture, Forestry, and Fishing",
+            Self::Mining => "Mining",
+            Self::Construction => "Construction",
+            Self::Manufacturing => "Manufacturing",
+            Self::TransportationUtilities => "Transportation, Communications, Electric, Gas, and Sanitary Services",
+            Self::WholesaleTrade => "Wholesale Trade",
+            Self::RetailTrade => "Retail Trade",
+            Self::FinanceInsuranceRealEstate => "Finance, Insurance, and Real Estate",
+            Self::Services => "Services",
+            Self::PublicAdministration => "Public Administration",
+        }
+    }
+}
+
+impl fmt::Display for SicDivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Map a SIC code to its division.
+///
+/// Returns `None` for empty or non-numeric codes and for codes outside the
+/// division ranges (e.g., 9995 "Non-Operating Establishments").
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::filings::sic::{sic_division, SicDivision};
+///
+/// assert_eq!(sic_division("3571"), Some(SicDivision::Manufacturing));
+/// assert_eq!(sic_division(""), None);
+/// ```
+pub fn sic_division(sic: &str) -> Option<SicDivision> {
+    let code: u16 = sic.trim().parse().ok()?;
+
+    let division = match code {
+        100..=999 => SicDivision::Agriculture,
+        1000..=1499 => SicDivision::Mining,
+        1500..=1799 => SicDivision::Construction,
+        2000..=3999 => SicDivision::Manufacturing,
+        4000..=4999 => SicDivision::TransportationUtilities,
+        5000..=5199 => SicDivision::WholesaleTrade,
+        5200..=5999 => SicDivision::RetailTrade,
+        6000..=6799 => SicDivision::FinanceInsuranceRealEstate,
+        7000..=8999 => SicDivision::Services,
+        9100..=9729 => SicDivision::PublicAdministration,
+        _ => return None,
+    };
+
+    Some(division)
+}
+
+/// Map a SIC code to its sector label.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::filings::sic::sic_sector;
+///
+/// assert_eq!(sic_sector("6022"), Some("Finance, Insurance, and Real Estate"));
+/// ```
+pub fn sic_sector(sic: &str) -> Option<&'static str> {
+    sic_division(sic).map(|division| division.label())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sic_division_representative_codes() {
+        assert_eq!(sic_division("0100"), Some(SicDivision::Agriculture));
+        assert_eq!(sic_division("1311"), Some(SicDivision::Mining));
+        assert_eq!(sic_division("3571"), Some(SicDivision::Manufacturing));
+        assert_eq!(sic_division("4813"), Some(SicDivision::TransportationUtilities));
+        assert_eq!(sic_division("5331"), Some(SicDivision::RetailTrade));
+        assert_eq!(sic_division("6022"), Some(SicDivision::FinanceInsuranceRealEstate));
+        assert_eq!(sic_division("7372"), Some(SicDivision::Services));
+        assert_eq!(sic_division(" 9721 "), Some(SicDivision::PublicAdministration));
+    }
+
+    #[test]
+    fn test_sic_division_unmapped_codes() {
+        assert_eq!(sic_division(""), None);
+        assert_eq!(sic_division("abc"), None);
+        assert_eq!(sic_division("1900"), None);
+        assert_eq!(sic_division("9995"), None);
+    }
+
+    #[test]
+    fn test_sic_sector_and_letter() {
+        assert_eq!(sic_sector("2834"), Some("Manufacturing"));
+        assert_eq!(sic_sector("5045"), Some("Wholesale Trade"));
+        assert_eq!(sic_division("7372").map(|d| d.letter()), Some('I'));
+    }
+}
@@ -0,0 +1,149 @@
+//! Local SQLite storage of filing metadata.
+//!
+//! Requires the `sqlite` feature. Filings go into a `filings` table keyed
+//! by CIK and accession number, so ingesting the same company twice
+//! updates rows instead of duplicating them.
+use rusqlite::{params, Connection};
+
+use super::Submissions;
+use crate::{Error, Result};
+
+const CREATE_TABLE: &str = "CREATE TABLE IF NOT EXISTS filings (
+    cik TEXT NOT NULL,
+    accession TEXT NOT NULL,
+    form TEXT NOT NULL,
+    filing_date TEXT,
+    report_date TEXT,
+    primary_document TEXT,
+    is_xbrl INTEGER NOT NULL,
+    size INTEGER,
+    PRIMARY KEY (cik, accession)
+)";
+
+const INSERT_FILING: &str = "INSERT OR REPLACE INTO filings
+    (cik, accession, form, filing_date, report_date, primary_document, is_xbrl, size)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
+
+/// Write a company's recent filings into the `filings` table.
+///
+/// Creates the table if needed and inserts every recent filing with an
+/// accession number in one transaction, replacing rows already stored for
+/// the same CIK and accession. Empty dates and documents are stored as
+/// `NULL`. Returns the number of rows written.
+///
+/// # Errors
+///
+/// Returns `Error::Custom` if SQLite rejects a statement; nothing is
+/// written in that case.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rusqlite::Connection;
+/// use sec_o3::Client;
+/// use sec_o3::filings::{get_submissions, ingest_filings_to_sqlite};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let submissions = get_submissions(&client, "320193").await?;
+///
+///     let conn = Connection::open("filings.db").expect("open database");
+///     let rows = ingest_filings_to_sqlite(&conn, &submissions)?;
+///     println!("Stored {} filings", rows);
+///     Ok(())
+/// }
+/// ```
+pub fn ingest_filings_to_sqlite(conn: &Connection, submissions: &Submissions) -> Result<usize> {
+    let recent = &submissions.filings.recent;
+    let tx = conn.unchecked_transaction().map_err(sqlite_error)?;
+    tx.execute(CREATE_TABLE, []).map_err(sqlite_error)?;
+
+    let mut rows = 0;
+    {
+        let mut insert = tx.prepare(INSERT_FILING).map_err(sqlite_error)?;
+        for (i, accession) in recent.accession_number.iter().enumerate() {
+            if accession.is_empty() {
+                continue;
+            }
+            let is_xbrl = recent.is_xbrl.get(i).is_some_and(|&flag| flag != 0);
+            insert
+                .execute(params![
+                    submissions.cik,
+                    accession,
+                    text(&recent.form, i).unwrap_or_default(),
+                    text(&recent.filing_date, i),
+                    text(&recent.report_date, i),
+                    text(&recent.primary_document, i),
+                    is_xbrl,
+                    recent.size.get(i),
+                ])
+                .map_err(sqlite_error)?;
+            rows += 1;
+        }
+    }
+    tx.commit().map_err(sqlite_error)?;
+
+    Ok(rows)
+}
+
+/// Entry `i` of a column, or `None` if it is missing or empty.
+fn text(values: &[String], i: usize) -> Option<&str> {
+    values.get(i).map(String::as_str).filter(|value| !value.is_empty())
+}
+
+fn sqlite_error(e: rusqlite::Error) -> Error {
+    Error::Custom(format!("SQLite error: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ingest_filings_to_sqlite() {
+        let submissions: Submissions = serde_json::from_value(json!({
+            "cik": "0000320193",
+            "entityType": "operating",
+            "sic": "3571",
+            "sicDescription": "Electronic Computers",
+            "name": "Apple Inc.",
+            "tickers": ["AAPL"],
+            "exchanges": ["Nasdaq"],
+            "filings": {
+                "recent": {
+                    "accessionNumber": ["0000320193-23-000106", "0000320193-23-000077", ""],
+                    "filingDate": ["2023-11-03", "2023-08-04", ""],
+                    "reportDate": ["2023-09-30", "", ""],
+                    "form": ["10-K", "8-K", "4"],
+                    "primaryDocument": ["aapl-20230930.htm", "aapl-20230803.htm", ""],
+                    "isXBRL": [1, 0, 0],
+                    "size": [9876543, 45678, 0]
+                }
+            }
+        }))
+        .unwrap();
+        let conn = Connection::open_in_memory().unwrap();
+
+        assert_eq!(ingest_filings_to_sqlite(&conn, &submissions).unwrap(), 2);
+        // Re-ingesting replaces rows rather than duplicating them
+        assert_eq!(ingest_filings_to_sqlite(&conn, &submissions).unwrap(), 2);
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM filings", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+
+        let (form, report_date, is_xbrl): (String, Option<String>, bool) = conn
+            .query_row(
+                "SELECT form, report_date, is_xbrl FROM filings WHERE accession = ?1",
+                ["0000320193-23-000077"],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(form, "8-K");
+        assert_eq!(report_date, None);
+        assert!(!is_xbrl);
+    }
+}
@@ -0,0 +1,242 @@
+//! XBRL instance downloads.
+//!
+//! Locates a filing's XBRL instance through its `FilingSummary.xml` and
+//! downloads it, rejecting truncated or non-XBRL responses before anything
+//! is written to disk. [`xbrl_coverage`] reports which financial
+//! statements the filing's XBRL covers before anything is parsed.
+//! [`download_xbrl_many`] fetches instances for many filings concurrently.
+use futures::stream::{self, StreamExt};
+use std::path::{Path, PathBuf};
+
+use super::{get_filing_summary, Filing};
+use crate::parse::filing_summary::XbrlCoverage;
+use crate::parse::xbrl::check_well_formed;
+use crate::{Client, Error, Result};
+
+/// Download the XBRL instance document of a filing.
+///
+/// The instance is validated with [`check_well_formed`], so a truncated
+/// body or an HTML error page is reported as an error.
+///
+/// # Errors
+///
+/// Returns `Error::NotFound` if the filing summary lists no instance, and
+/// `Error::XmlError` if the downloaded document is not a well-formed
+/// XBRL instance.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::{download_xbrl_instance, get_recent_filings};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "320193").await?;
+///     if let Some(filing) = filings.iter().find(|f| f.is_xbrl) {
+///         let xml = download_xbrl_instance(&client, filing).await?;
+///         println!("{} bytes", xml.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn download_xbrl_instance(client: &Client, filing: &Filing) -> Result<String> {
+    let summary = get_filing_summary(client, filing).await?;
+    let instance = summary.instance_file().ok_or_else(|| {
+        Error::NotFound(format!(
+            "No XBRL instance listed for filing {}",
+            filing.accession_number
+        ))
+    })?;
+
+    let xml = client.get_text(&format!("{}{}", filing.base_url(), instance)).await?;
+    check_well_formed(&xml)?;
+
+    Ok(xml)
+}
+
+/// Download the XBRL instances of many filings concurrently.
+///
+/// At most `concurrency` filings are downloaded at once (at least one),
+/// and the client's rate limit applies across all of them. Filings not
+/// flagged as XBRL are skipped without a request and yield `Ok(None)`.
+/// Each item pairs the accession number with its result, so one failed
+/// download does not affect the others. Results are in completion order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::{download_xbrl_many, get_recent_filings};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "320193").await?;
+///
+///     for (accession, result) in download_xbrl_many(&client, &filings, 4).await {
+///         match result {
+///             Ok(Some(xml)) => println!("{}: {} bytes", accession, xml.len()),
+///             Ok(None) => println!("{}: no XBRL", accession),
+///             Err(e) => eprintln!("{}: {}", accession, e),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn download_xbrl_many(
+    client: &Client,
+    filings: &[Filing],
+    concurrency: usize,
+) -> Vec<(String, Result<Option<String>>)> {
+    stream::iter(filings)
+        .map(|filing| async move {
+            let result = if filing.is_xbrl {
+                download_xbrl_instance(client, filing).await.map(Some)
+            } else {
+                Ok(None)
+            };
+            (filing.accession_number.clone(), result)
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Download a filing's XBRL instance into `output_dir`.
+///
+/// The file is written only after the instance passes validation, so an
+/// invalid download never leaves a partial file behind.
+///
+/// # Returns
+///
+/// Path to the written instance, named `<accession>_xbrl.xml`.
+pub async fn download_xbrl_to_file(client: &Client, filing: &Filing, output_dir: impl AsRef<Path>) -> Result<PathBuf> {
+    let xml = download_xbrl_instance(client, filing).await?;
+
+    let output_dir = output_dir.as_ref();
+    tokio::fs::create_dir_all(output_dir).await.map_err(Error::IoError)?;
+
+    let output_path = output_dir.join(format!("{}_xbrl.xml", filing.accession_number));
+    tokio::fs::write(&output_path, xml).await.map_err(Error::IoError)?;
+
+    Ok(output_path)
+}
+
+/// Report which financial statements a filing's XBRL covers.
+///
+/// Reads the filing's `FilingSummary.xml` and classifies its statement
+/// R-reports (see [`FilingSummary::coverage`](crate::parse::filing_summary::FilingSummary::coverage)).
+///
+/// # Examples
+///
+/// ```no_run
+/// use sec_o3::Client;
+/// use sec_o3::filings::{get_recent_filings, xbrl_coverage};
+///
+/// #[tokio::main]
+/// async fn main() -> sec_o3::Result<()> {
+///     let client = Client::new("MyApp", "contact@example.com");
+///     let filings = get_recent_filings(&client, "320193").await?;
+///     if let Some(filing) = filings.iter().find(|f| f.is_xbrl) {
+///         let coverage = xbrl_coverage(&client, filing).await?;
+///         println!("Cash flow tagged: {}", coverage.cash_flow);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn xbrl_coverage(client: &Client, filing: &Filing) -> Result<XbrlCoverage> {
+    Ok(get_filing_summary(client, filing).await?.coverage())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_client, sample_filing};
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const FILING_DIR: &str = "/Archives/edgar/data/320193/000032019323000106";
+
+    const INSTANCE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<xbrli:xbrl xmlns:xbrli="http://www.xbrl.org/2003/instance">
+  <us-gaap:Revenues contextRef="FY2023" unitRef="usd" decimals="-6">383285000000</us-gaap:Revenues>
+</xbrli:xbrl>"#;
+
+    async fn mock_filing(instance_body: &str) -> MockServer {
+        let server = MockServer::start().await;
+        let summary = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/FilingSummary.xml"));
+
+        Mock::given(path(format!("{}/FilingSummary.xml", FILING_DIR)))
+            .respond_with(ResponseTemplate::new(200).set_body_string(summary))
+            .mount(&server)
+            .await;
+        Mock::given(path(format!("{}/aapl-20230930_htm.xml", FILING_DIR)))
+            .respond_with(ResponseTemplate::new(200).set_body_string(instance_body))
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn test_download_xbrl_to_file_writes_valid_instance() {
+        let server = mock_filing(INSTANCE).await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = download_xbrl_to_file(&mock_client(&server), &sample_filing(), dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read_to_string(path).unwrap(), INSTANCE);
+    }
+
+    #[tokio::test]
+    async fn test_download_xbrl_to_file_rejects_truncated_instance() {
+        let truncated = &INSTANCE[..INSTANCE.len() - 20];
+        let server = mock_filing(truncated).await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let result = download_xbrl_to_file(&mock_client(&server), &sample_filing(), dir.path()).await;
+
+        assert!(matches!(result, Err(Error::XmlError(_))));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_download_xbrl_many_mixes_xbrl_and_plain_filings() {
+        let server = mock_filing(INSTANCE).await;
+        let plain = Filing {
+            accession_number: "0000320193-23-000077".to_string(),
+            form_type: "8-K".to_string(),
+            is_xbrl: false,
+            ..sample_filing()
+        };
+        let missing = Filing {
+            accession_number: "0000320193-23-000064".to_string(),
+            ..sample_filing()
+        };
+
+        let results = download_xbrl_many(&mock_client(&server), &[sample_filing(), plain, missing], 2).await;
+        let result = |accession: &str| &results.iter().find(|(a, _)| a == accession).unwrap().1;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            result("0000320193-23-000106").as_ref().unwrap().as_deref(),
+            Some(INSTANCE)
+        );
+        assert!(matches!(result("0000320193-23-000077"), Ok(None)));
+        assert!(matches!(result("0000320193-23-000064"), Err(Error::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_xbrl_coverage() {
+        let server = mock_filing(INSTANCE).await;
+
+        let coverage = xbrl_coverage(&mock_client(&server), &sample_filing()).await.unwrap();
+
+        assert!(coverage.income_statement && coverage.balance_sheet && coverage.cash_flow && coverage.equity);
+        assert_eq!(coverage.statements.len(), 5);
+    }
+}
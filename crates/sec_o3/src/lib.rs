@@ -32,15 +32,22 @@
 pub mod client;
 /// - `errors` - Unified error handling
 pub mod errors;
+/// - `facts` - Types and helpers for XBRL company facts.
+pub mod facts;
 /// - `filings` - Functions for fetching and downloading filings.
 pub mod filings;
+/// - `parse` - Parsers for downloaded filing documents.
+pub mod parse;
 /// - `utils` - Utility functions for standardizing dates and retrieving CIKs.
 pub mod utils;
 
 #[cfg(feature = "python")]
 pub mod python;
 
-pub use client::Client;
+#[cfg(test)]
+mod test_utils;
+
+pub use client::{Client, ClientBuilder};
 pub use errors::{Error, Result};
 
 /// Library version
@@ -0,0 +1,121 @@
+//! Auditor extraction from annual reports.
+//!
+//! Every 10-K contains a "Report of Independent Registered Public Accounting
+//! Firm" signed by the audit firm, and since 2022 filings also disclose the
+//! firm's PCAOB ID.
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Audit firm named in an annual report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Auditor {
+    /// Firm name as signed (e.g., "Ernst & Young LLP")
+    pub name: String,
+    /// PCAOB firm identifier, when disclosed (e.g., "42")
+    pub pcaob_id: Option<String>,
+}
+
+/// Number of characters after the report heading searched for a signature.
+const REPORT_WINDOW: usize = 20_000;
+
+static REPORT_HEADING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)report\s+of\s+independent\s+registered\s+public\s+accounting\s+firm").unwrap());
+
+static SIGNATURE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"/s/\s*([A-Z][A-Za-z&.,'\- ]*?\b(?:LLP|L\.L\.P\.|LLC|PLLC|P\.C\.|P\.A\.|Ltd\.?|AG|GmbH|S\.A\.))")
+        .unwrap()
+});
+
+static FIRM_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^\s*([A-Z][A-Za-z&.,'\- ]*?\b(?:LLP|L\.L\.P\.|LLC|PLLC|P\.C\.|P\.A\.))\s*$").unwrap()
+});
+
+static PCAOB_ID: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:PCAOB\s+(?:Firm\s+)?ID(?:\s+No\.?)?|Auditor\s+Firm\s+ID)\s*[:#]?\s*(\d+)").unwrap()
+});
+
+/// Extract the audit firm from the text of a 10-K.
+///
+/// Looks for the "Report of Independent Registered Public Accounting Firm"
+/// section and takes the firm name from its `/s/` signature (or a standalone
+/// line ending in a firm suffix such as "LLP"). The PCAOB ID is read from
+/// anywhere in the text. Returns `None` if no audit report is present.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::auditor::extract_auditor;
+///
+/// let text = "Report of Independent Registered Public Accounting Firm\n\
+///             ...\n/s/ Ernst & Young LLP\nWe have served as the Company's auditor since 2009.";
+///
+/// let auditor = extract_auditor(text).unwrap();
+/// assert_eq!(auditor.name, "Ernst & Young LLP");
+/// ```
+pub fn extract_auditor(text: &str) -> Option<Auditor> {
+    let heading = REPORT_HEADING.find(text)?;
+
+    let mut end = (heading.end() + REPORT_WINDOW).min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let report = &text[heading.end()..end];
+
+    let name = SIGNATURE
+        .captures(report)
+        .or_else(|| FIRM_NAME.captures(report))
+        .map(|caps| caps[1].split_whitespace().collect::<Vec<_>>().join(" "))?;
+
+    let pcaob_id = PCAOB_ID.captures(text).map(|caps| caps[1].to_string());
+
+    Some(Auditor { name, pcaob_id })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUDIT_REPORT: &str = "\
+ITEM 8. FINANCIAL STATEMENTS AND SUPPLEMENTARY DATA
+
+Report of Independent Registered Public Accounting Firm
+
+To the Shareholders and the Board of Directors of Example Corp.
+
+Opinion on the Financial Statements
+We have audited the accompanying consolidated balance sheets of Example Corp. as of
+September 30, 2023 and September 24, 2022 ... present fairly, in all material respects.
+
+/s/ Ernst & Young LLP
+
+We have served as the Company's auditor since 2009.
+San Jose, California
+November 2, 2023
+
+Auditor Firm ID: 42";
+
+    #[test]
+    fn test_extract_auditor_from_signature() {
+        let auditor = extract_auditor(AUDIT_REPORT).expect("auditor should be found");
+        assert_eq!(auditor.name, "Ernst & Young LLP");
+        assert_eq!(auditor.pcaob_id.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_extract_auditor_unsigned_firm_line() {
+        let text = "REPORT OF INDEPENDENT REGISTERED PUBLIC ACCOUNTING FIRM\n\
+                    In our opinion, the financial statements present fairly...\n\
+                    Deloitte & Touche LLP\n\
+                    Boston, Massachusetts\n\
+                    (PCAOB ID No. 34)";
+
+        let auditor = extract_auditor(text).expect("auditor should be found");
+        assert_eq!(auditor.name, "Deloitte & Touche LLP");
+        assert_eq!(auditor.pcaob_id.as_deref(), Some("34"));
+    }
+
+    #[test]
+    fn test_extract_auditor_absent() {
+        assert_eq!(extract_auditor("FORM 8-K\nItem 2.02 Results of Operations"), None);
+    }
+}
@@ -0,0 +1,207 @@
+//! Chunking of long filing text for NLP models.
+//!
+//! Embedding and language models accept bounded input, so 10-K text must be
+//! cut into segments. Chunks end at paragraph or sentence boundaries where
+//! possible and never inside a word.
+
+/// Strength of the boundary following a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Boundary {
+    Word,
+    Sentence,
+    Paragraph,
+}
+
+/// A word's position in the text, in bytes and in characters.
+struct Word {
+    byte_start: usize,
+    byte_end: usize,
+    char_start: usize,
+    char_end: usize,
+    boundary: Boundary,
+}
+
+/// Split text into chunks of at most `max_chars` characters.
+///
+/// Each chunk ends at the last paragraph break that fits, else the last
+/// sentence end, else the last whole word, as long as that keeps the chunk
+/// at least half full. Consecutive chunks share up to `overlap` characters
+/// of whole words. Original whitespace is kept inside chunks.
+///
+/// A single word longer than `max_chars` becomes its own chunk rather than
+/// being split.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::chunk::chunk_text;
+///
+/// let text = "Revenue grew. Margins fell.\n\nCash was stable.";
+/// let chunks = chunk_text(text, 30, 0);
+/// assert_eq!(chunks, vec!["Revenue grew. Margins fell.", "Cash was stable."]);
+/// ```
+pub fn chunk_text(text: &str, max_chars: usize, overlap: usize) -> Vec<String> {
+    let words = words(text);
+    let len = |from: usize, to: usize| words[to - 1].char_end - words[from].char_start;
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < words.len() {
+        // Longest run of whole words that fits (at least one word)
+        let mut end = start + 1;
+        while end < words.len() && len(start, end + 1) <= max_chars {
+            end += 1;
+        }
+
+        if end < words.len() {
+            let strongest = (start + 1..=end)
+                .filter(|&i| len(start, i) * 2 >= max_chars)
+                .max_by_key(|&i| (words[i - 1].boundary, i));
+            if let Some(cut) = strongest {
+                end = cut;
+            }
+        }
+
+        chunks.push(text[words[start].byte_start..words[end - 1].byte_end].to_string());
+        if end == words.len() {
+            break;
+        }
+
+        // Back up over whole words for the overlap, always moving forward
+        let mut next = end;
+        while next - 1 > start && len(next - 1, end) <= overlap {
+            next -= 1;
+        }
+        start = next;
+    }
+
+    chunks
+}
+
+/// Locate the words of `text` and the boundary after each.
+fn words(text: &str) -> Vec<Word> {
+    let mut words: Vec<Word> = Vec::new();
+    let mut word_start: Option<(usize, usize)> = None;
+    let mut newlines = 0;
+
+    for (char_index, (byte_index, c)) in text.char_indices().enumerate() {
+        if c.is_whitespace() {
+            if let Some((byte_start, char_start)) = word_start.take() {
+                words.push(Word {
+                    byte_start,
+                    byte_end: byte_index,
+                    char_start,
+                    char_end: char_index,
+                    boundary: Boundary::Word,
+                });
+                newlines = 0;
+            }
+            if c == '\n' {
+                newlines += 1;
+            }
+            continue;
+        }
+
+        if word_start.is_none() {
+            if let Some(previous) = words.last_mut() {
+                previous.boundary = boundary_after(&text[previous.byte_start..previous.byte_end], newlines);
+            }
+            word_start = Some((byte_index, char_index));
+        }
+    }
+
+    if let Some((byte_start, char_start)) = word_start {
+        words.push(Word {
+            byte_start,
+            byte_end: text.len(),
+            char_start,
+            char_end: text.chars().count(),
+            boundary: Boundary::Paragraph,
+        });
+    }
+
+    words
+}
+
+fn boundary_after(word: &str, newlines: usize) -> Boundary {
+    let ends_sentence = word
+        .trim_end_matches(['"', '\'', ')', '”', '’'])
+        .ends_with(['.', '!', '?', ':', ';']);
+
+    if newlines >= 2 || (newlines == 1 && ends_sentence) {
+        Boundary::Paragraph
+    } else if ends_sentence {
+        Boundary::Sentence
+    } else {
+        Boundary::Word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Item 7. Management's Discussion and Analysis\n\n\
+        The Company's fiscal year is the 52- or 53-week period that ends on the last Saturday of September. \
+        Net sales decreased 3% during 2023 compared to 2022.\n\n\
+        The weakness in foreign currencies relative to the U.S. dollar had an unfavorable year-over-year \
+        impact on all Segment net sales. Services net sales increased during 2023.";
+
+    fn original_words() -> Vec<&'static str> {
+        TEXT.split_whitespace().collect()
+    }
+
+    #[test]
+    fn test_chunks_respect_limit_and_word_boundaries() {
+        for max_chars in [40, 80, 150, 1000] {
+            let chunks = chunk_text(TEXT, max_chars, 0);
+
+            assert!(chunks.iter().all(|c| c.chars().count() <= max_chars), "{:?}", chunks);
+            let rejoined: Vec<&str> = chunks.iter().flat_map(|c| c.split_whitespace()).collect();
+            assert_eq!(rejoined, original_words());
+        }
+
+        assert_eq!(chunk_text(TEXT, 1000, 0), vec![TEXT.to_string()]);
+        assert!(chunk_text("   ", 10, 0).is_empty());
+    }
+
+    #[test]
+    fn test_chunks_prefer_paragraph_and_sentence_ends() {
+        // The heading alone would leave the chunk under half full
+        let chunks = chunk_text(TEXT, 160, 0);
+        assert!(chunks[0].starts_with("Item 7."));
+        assert!(chunks[0].ends_with("last Saturday of September."));
+
+        let chunks = chunk_text(TEXT, 80, 0);
+        assert_eq!(chunks[0], "Item 7. Management's Discussion and Analysis");
+    }
+
+    #[test]
+    fn test_overlap_is_respected() {
+        let chunks = chunk_text(TEXT, 80, 20);
+
+        for pair in chunks.windows(2) {
+            let (previous, next) = (&pair[0], &pair[1]);
+            let shared = (1..=next.len().min(previous.len()))
+                .rev()
+                .find(|&n| next.is_char_boundary(n) && previous.ends_with(&next[..n]))
+                .unwrap_or(0);
+
+            assert!(shared > 0, "no overlap between {:?} and {:?}", previous, next);
+            assert!(next[..shared].chars().count() <= 20);
+            assert!(next.chars().count() <= 80);
+        }
+
+        let words: Vec<&str> = chunks.iter().flat_map(|c| c.split_whitespace()).collect();
+        assert!(words.len() > original_words().len());
+    }
+
+    #[test]
+    fn test_long_word_is_not_split() {
+        let chunks = chunk_text("see https://www.sec.gov/Archives/edgar/data/320193/ now", 10, 0);
+        assert_eq!(
+            chunks,
+            vec!["see", "https://www.sec.gov/Archives/edgar/data/320193/", "now"]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! SEC structured dataset files.
+//!
+//! Parses the tab-separated files inside the SEC's quarterly insider
+//! transactions datasets (see
+//! [`download_insider_dataset`](crate::filings::download_insider_dataset)).
+//! Columns are located by their header names, so extra or reordered
+//! columns in newer releases are tolerated.
+use chrono::NaiveDate;
+
+/// One row of `NONDERIV_TRANS.tsv`: a non-derivative transaction reported on Form 3, 4, or 5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonDerivTransaction {
+    /// Accession number of the reporting filing
+    pub accession_number: String,
+    /// Title of the security (e.g., "Common Stock")
+    pub security_title: String,
+    /// Date of the transaction
+    pub trans_date: Option<NaiveDate>,
+    /// Form type of the reporting filing (e.g., "4")
+    pub trans_form_type: String,
+    /// Transaction code (e.g., "P" for purchase, "S" for sale)
+    pub trans_code: String,
+    /// Number of shares transacted
+    pub shares: Option<f64>,
+    /// Price per share
+    pub price_per_share: Option<f64>,
+    /// "A" if the shares were acquired, "D" if disposed of
+    pub acquired_disposed: String,
+    /// Shares owned after the transaction
+    pub shares_owned_following: Option<f64>,
+    /// "D" for direct ownership, "I" for indirect
+    pub direct_indirect: String,
+}
+
+/// Parse the `NONDERIV_TRANS.tsv` file of an insider transactions dataset.
+///
+/// The first line must be the header row. Rows with fewer fields than the
+/// header are skipped. Empty or unparseable numbers and dates are `None`;
+/// dates are in the datasets' `DD-MON-YYYY` format (e.g., "15-NOV-2023").
+/// Returns an empty list if the header lacks an `ACCESSION_NUMBER` column.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::datasets::parse_nonderiv_trans;
+///
+/// let tsv = "ACCESSION_NUMBER\tTRANS_CODE\tTRANS_SHARES\n\
+///            0001127602-23-030000\tS\t10000\n";
+///
+/// let rows = parse_nonderiv_trans(tsv);
+/// assert_eq!(rows[0].trans_code, "S");
+/// assert_eq!(rows[0].shares, Some(10_000.0));
+/// ```
+pub fn parse_nonderiv_trans(tsv: &str) -> Vec<NonDerivTransaction> {
+    let mut lines = tsv.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.split('\t').map(str::trim).collect();
+    let column = |name: &str| columns.iter().position(|c| c.eq_ignore_ascii_case(name));
+    let Some(accession) = column("ACCESSION_NUMBER") else {
+        return Vec::new();
+    };
+
+    let security_title = column("SECURITY_TITLE");
+    let trans_date = column("TRANS_DATE");
+    let trans_form_type = column("TRANS_FORM_TYPE");
+    let trans_code = column("TRANS_CODE");
+    let shares = column("TRANS_SHARES");
+    let price_per_share = column("TRANS_PRICEPERSHARE");
+    let acquired_disposed = column("TRANS_ACQUIRED_DISP_CD");
+    let shares_owned_following = column("SHRS_OWND_FOLWNG_TRANS");
+    let direct_indirect = column("DIRECT_INDIRECT_OWNERSHIP");
+
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < columns.len() {
+                return None;
+            }
+            let text = |index: Option<usize>| index.map_or("", |i| fields[i].trim()).to_string();
+            let number = |index: Option<usize>| index.and_then(|i| fields[i].trim().parse::<f64>().ok());
+
+            Some(NonDerivTransaction {
+                accession_number: text(Some(accession)),
+                security_title: text(security_title),
+                trans_date: trans_date.and_then(|i| NaiveDate::parse_from_str(fields[i].trim(), "%d-%b-%Y").ok()),
+                trans_form_type: text(trans_form_type),
+                trans_code: text(trans_code),
+                shares: number(shares),
+                price_per_share: number(price_per_share),
+                acquired_disposed: text(acquired_disposed),
+                shares_owned_following: number(shares_owned_following),
+                direct_indirect: text(direct_indirect),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed from 2023q4_form345.zip; footnote columns and unused fields removed
+    const NONDERIV_TRANS: &str = "\
+ACCESSION_NUMBER\tNONDERIV_TRANS_SK\tSECURITY_TITLE\tTRANS_DATE\tTRANS_FORM_TYPE\tTRANS_CODE\tTRANS_SHARES\tTRANS_PRICEPERSHARE\tTRANS_ACQUIRED_DISP_CD\tSHRS_OWND_FOLWNG_TRANS\tDIRECT_INDIRECT_OWNERSHIP
+0000320193-23-000108\t5819235\tCommon Stock\t15-OCT-2023\t4\tM\t59162\t\tA\t3339543\tD
+0000320193-23-000108\t5819236\tCommon Stock\t15-OCT-2023\t4\tF\t31437\t178.85\tD\t3308106\tD
+0001209191-23-058813\t5820011\tClass A Common Stock\t02-NOV-2023\t4\tS\t1200\t129.515\tD\t\tI
+0001209191-23-058813\t5820012\ttruncated row
+";
+
+    #[test]
+    fn test_parse_nonderiv_trans() {
+        let rows = parse_nonderiv_trans(NONDERIV_TRANS);
+
+        assert_eq!(rows.len(), 3);
+
+        let vested = &rows[0];
+        assert_eq!(vested.accession_number, "0000320193-23-000108");
+        assert_eq!(vested.trans_date, NaiveDate::from_ymd_opt(2023, 10, 15));
+        assert_eq!(vested.trans_code, "M");
+        assert_eq!(vested.shares, Some(59_162.0));
+        assert_eq!(vested.price_per_share, None);
+        assert_eq!(vested.acquired_disposed, "A");
+
+        let sale = &rows[2];
+        assert_eq!(sale.security_title, "Class A Common Stock");
+        assert_eq!(sale.trans_form_type, "4");
+        assert_eq!(sale.price_per_share, Some(129.515));
+        assert_eq!(sale.shares_owned_following, None);
+        assert_eq!(sale.direct_indirect, "I");
+
+        assert!(parse_nonderiv_trans("SECURITY_TITLE\nCommon Stock\n").is_empty());
+        assert!(parse_nonderiv_trans("").is_empty());
+    }
+}
@@ -0,0 +1,359 @@
+//! Parsing of EDGAR `FilingSummary.xml`.
+//!
+//! Every XBRL filing directory contains a `FilingSummary.xml` produced by the
+//! SEC renderer. It lists the rendered R-reports (cover page, statements,
+//! notes) and the input files that make up the XBRL package.
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::resolve_xml_entity;
+use crate::{Error, Result};
+
+/// Contents of a `FilingSummary.xml` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilingSummary {
+    /// Form type of the filing (e.g., "10-K")
+    pub report_type: Option<String>,
+    /// Rendered reports, in document order
+    pub reports: Vec<Report>,
+    /// Files making up the XBRL package (instance, schema, linkbases)
+    pub input_files: Vec<String>,
+}
+
+impl FilingSummary {
+    /// Name of the XBRL instance document, if it can be determined.
+    ///
+    /// Prefers an extracted inline XBRL instance (`*_htm.xml`), then a
+    /// traditional instance (`.xml` other than the linkbases). For inline
+    /// XBRL filings that list only the `.htm` document, returns the name
+    /// EDGAR gives the extracted instance (e.g., `aapl-20230930_htm.xml`).
+    pub fn instance_file(&self) -> Option<String> {
+        const LINKBASES: [&str; 4] = ["_cal.xml", "_def.xml", "_lab.xml", "_pre.xml"];
+
+        let files = &self.input_files;
+        files
+            .iter()
+            .find(|f| f.ends_with("_htm.xml"))
+            .or_else(|| {
+                files
+                    .iter()
+                    .find(|f| f.ends_with(".xml") && !LINKBASES.iter().any(|suffix| f.ends_with(suffix)))
+            })
+            .cloned()
+            .or_else(|| {
+                files
+                    .iter()
+                    .find_map(|f| f.strip_suffix(".htm"))
+                    .map(|stem| format!("{}_htm.xml", stem))
+            })
+    }
+
+    /// Which primary financial statements have rendered reports.
+    ///
+    /// Only statement reports are considered: those in the "Statements"
+    /// menu category, or whose long name marks them as a statement when
+    /// the category is absent. Each is classified by its short name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec_o3::parse::filing_summary::parse;
+    ///
+    /// let xml = r#"<FilingSummary><MyReports>
+    ///   <Report><ShortName>CONSOLIDATED BALANCE SHEETS</ShortName><MenuCategory>Statements</MenuCategory></Report>
+    /// </MyReports></FilingSummary>"#;
+    ///
+    /// let coverage = parse(xml).unwrap().coverage();
+    /// assert!(coverage.balance_sheet);
+    /// assert!(!coverage.cash_flow);
+    /// ```
+    pub fn coverage(&self) -> XbrlCoverage {
+        let mut coverage = XbrlCoverage::default();
+
+        for report in self.reports.iter().filter(|r| r.is_statement()) {
+            match StatementKind::classify(&report.short_name) {
+                Some(StatementKind::Income) => coverage.income_statement = true,
+                Some(StatementKind::BalanceSheet) => coverage.balance_sheet = true,
+                Some(StatementKind::CashFlow) => coverage.cash_flow = true,
+                Some(StatementKind::Equity) => coverage.equity = true,
+                None => {}
+            }
+            coverage.statements.push(report.short_name.clone());
+        }
+
+        coverage
+    }
+}
+
+/// Which financial statements a filing's XBRL rendering covers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XbrlCoverage {
+    /// Income statement (operations, earnings, or income)
+    pub income_statement: bool,
+    /// Balance sheet (financial position or condition)
+    pub balance_sheet: bool,
+    /// Statement of cash flows
+    pub cash_flow: bool,
+    /// Statement of shareholders' or stockholders' equity
+    pub equity: bool,
+    /// Short names of all statement reports, in document order
+    pub statements: Vec<String>,
+}
+
+/// Primary financial statement a report renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatementKind {
+    Income,
+    BalanceSheet,
+    CashFlow,
+    Equity,
+}
+
+impl StatementKind {
+    /// Classify a statement report by its short name.
+    fn classify(short_name: &str) -> Option<Self> {
+        let name = short_name.to_uppercase();
+        let has = |needle: &str| name.contains(needle);
+        // A standalone statement of comprehensive income is not the income statement
+        let comprehensive_only = has("COMPREHENSIVE INCOME") && !has("INCOME AND COMPREHENSIVE");
+
+        if has("CASH FLOW") {
+            Some(Self::CashFlow)
+        } else if has("BALANCE SHEET") || has("FINANCIAL POSITION") || has("FINANCIAL CONDITION") {
+            Some(Self::BalanceSheet)
+        } else if has("EQUITY") || has("STOCKHOLDERS") || has("SHAREHOLDERS") || has("PARTNERS' CAPITAL") {
+            Some(Self::Equity)
+        } else if has("OPERATIONS") || has("EARNINGS") || (has("INCOME") && !comprehensive_only) {
+            Some(Self::Income)
+        } else {
+            None
+        }
+    }
+}
+
+/// A single rendered report (R-file) listed in the filing summary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Short display name (e.g., "CONSOLIDATED BALANCE SHEETS")
+    pub short_name: String,
+    /// Long name including sequence and category (e.g., "0000005 - Statement - ...")
+    pub long_name: String,
+    /// Role URI of the presentation group
+    pub role: Option<String>,
+    /// Rendered HTML file name (e.g., "R5.htm")
+    pub html_file: Option<String>,
+    /// Rendered XML file name, used by older filings (e.g., "R5.xml")
+    pub xml_file: Option<String>,
+    /// Menu category (e.g., "Cover", "Statements", "Notes")
+    pub menu_category: Option<String>,
+}
+
+impl Report {
+    /// Whether the report renders a financial statement (not a cover page or note).
+    fn is_statement(&self) -> bool {
+        match &self.menu_category {
+            Some(category) => category.eq_ignore_ascii_case("Statements"),
+            None => self.long_name.contains(" - Statement - "),
+        }
+    }
+}
+
+/// Parse a `FilingSummary.xml` document.
+///
+/// # Errors
+///
+/// Returns `Error::XmlError` if the document is not well-formed XML or
+/// has no `FilingSummary` root element.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::filing_summary::parse;
+///
+/// let xml = r#"<FilingSummary>
+///   <MyReports>
+///     <Report><ShortName>Cover Page</ShortName><HtmlFileName>R1.htm</HtmlFileName></Report>
+///   </MyReports>
+///   <InputFiles><File>aapl-20230930.htm</File></InputFiles>
+/// </FilingSummary>"#;
+///
+/// let summary = parse(xml).unwrap();
+/// assert_eq!(summary.reports[0].html_file.as_deref(), Some("R1.htm"));
+/// assert_eq!(summary.input_files, vec!["aapl-20230930.htm"]);
+/// ```
+pub fn parse(xml: &str) -> Result<FilingSummary> {
+    let mut reader = Reader::from_str(xml);
+
+    let mut summary = FilingSummary::default();
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+    let mut report: Option<Report> = None;
+    let mut seen_root = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                if path.is_empty() {
+                    seen_root = name == "FilingSummary";
+                }
+                if name == "Report" && path.len() == 2 {
+                    report = Some(Report::default());
+                }
+                path.push(name);
+                text.clear();
+            }
+            Ok(Event::Text(t)) => text.push_str(&t.decode().map_err(|e| Error::XmlError(e.to_string()))?),
+            Ok(Event::GeneralRef(entity)) => text.push_str(&resolve_xml_entity(&entity)?),
+            Ok(Event::End(_)) => {
+                let value = text.trim().to_string();
+                text.clear();
+
+                let segments: Vec<&str> = path.iter().map(String::as_str).collect();
+                match segments.as_slice() {
+                    ["FilingSummary", "ReportType"] => summary.report_type = Some(value),
+                    ["FilingSummary", "InputFiles", "File"] => summary.input_files.push(value),
+                    ["FilingSummary", "MyReports", "Report"] => summary.reports.extend(report.take()),
+                    ["FilingSummary", "MyReports", "Report", field] => {
+                        if let Some(report) = report.as_mut() {
+                            match *field {
+                                "ShortName" => report.short_name = value,
+                                "LongName" => report.long_name = value,
+                                "Role" => report.role = Some(value),
+                                "HtmlFileName" => report.html_file = Some(value),
+                                "XmlFileName" => report.xml_file = Some(value),
+                                "MenuCategory" => report.menu_category = Some(value),
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+
+                path.pop();
+            }
+            Ok(Event::Eof) if path.is_empty() => break,
+            Ok(Event::Eof) => return Err(Error::XmlError("Unexpected end of document".to_string())),
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlError(e.to_string())),
+        }
+    }
+
+    if !seen_root {
+        return Err(Error::XmlError("Missing FilingSummary root element".to_string()));
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILING_SUMMARY: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/FilingSummary.xml"));
+
+    #[test]
+    fn test_parse_captured_filing_summary() {
+        let summary = parse(FILING_SUMMARY).expect("fixture should parse");
+
+        assert_eq!(summary.report_type.as_deref(), Some("10-K"));
+        assert_eq!(summary.reports.len(), 9);
+        assert_eq!(summary.input_files.len(), 6);
+        assert_eq!(summary.input_files[5], "aapl-20230930.htm");
+
+        let balance_sheet = &summary.reports[4];
+        assert_eq!(balance_sheet.short_name, "CONSOLIDATED BALANCE SHEETS");
+        assert_eq!(
+            balance_sheet.long_name,
+            "0000005 - Statement - CONSOLIDATED BALANCE SHEETS"
+        );
+        assert_eq!(
+            balance_sheet.role.as_deref(),
+            Some("http://www.apple.com/role/CONSOLIDATEDBALANCESHEETS")
+        );
+        assert_eq!(balance_sheet.html_file.as_deref(), Some("R5.htm"));
+        assert_eq!(balance_sheet.xml_file, None);
+        assert_eq!(balance_sheet.menu_category.as_deref(), Some("Statements"));
+
+        assert_eq!(
+            summary.reports[5].short_name,
+            "CONSOLIDATED STATEMENTS OF SHAREHOLDERS' EQUITY"
+        );
+
+        let all_reports = summary.reports.last().unwrap();
+        assert_eq!(all_reports.short_name, "All Reports");
+        assert_eq!(all_reports.html_file, None);
+
+        assert_eq!(summary.instance_file().as_deref(), Some("aapl-20230930_htm.xml"));
+    }
+
+    #[test]
+    fn test_instance_file_traditional_xbrl() {
+        let summary = FilingSummary {
+            input_files: vec![
+                "msft-20100630.xsd".to_string(),
+                "msft-20100630_cal.xml".to_string(),
+                "msft-20100630_lab.xml".to_string(),
+                "msft-20100630.xml".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(summary.instance_file().as_deref(), Some("msft-20100630.xml"));
+        assert_eq!(FilingSummary::default().instance_file(), None);
+    }
+
+    #[test]
+    fn test_coverage_of_captured_filing_summary() {
+        let coverage = parse(FILING_SUMMARY).unwrap().coverage();
+
+        assert!(coverage.income_statement);
+        assert!(coverage.balance_sheet);
+        assert!(coverage.cash_flow);
+        assert!(coverage.equity);
+        assert_eq!(
+            coverage.statements,
+            vec![
+                "CONSOLIDATED STATEMENTS OF OPERATIONS",
+                "CONSOLIDATED STATEMENTS OF COMPREHENSIVE INCOME",
+                "CONSOLIDATED BALANCE SHEETS",
+                "CONSOLIDATED STATEMENTS OF SHAREHOLDERS' EQUITY",
+                "CONSOLIDATED STATEMENTS OF CASH FLOWS",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coverage_classification() {
+        assert_eq!(
+            StatementKind::classify("Consolidated Statements of Income"),
+            Some(StatementKind::Income)
+        );
+        assert_eq!(
+            StatementKind::classify("Statements of Income and Comprehensive Income"),
+            Some(StatementKind::Income)
+        );
+        assert_eq!(StatementKind::classify("Statements of Comprehensive Income"), None);
+        assert_eq!(
+            StatementKind::classify("Statements of Financial Condition"),
+            Some(StatementKind::BalanceSheet)
+        );
+
+        let summary = FilingSummary {
+            reports: vec![Report {
+                short_name: "CONSOLIDATED BALANCE SHEETS".to_string(),
+                long_name: "0000002 - Statement - CONSOLIDATED BALANCE SHEETS".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let coverage = summary.coverage();
+        assert!(coverage.balance_sheet);
+        assert!(!coverage.income_statement && !coverage.cash_flow && !coverage.equity);
+    }
+
+    #[test]
+    fn test_parse_rejects_non_summary() {
+        assert!(parse("<html><body>Not found</body></html>").is_err());
+        assert!(parse("<FilingSummary><MyReports>").is_err());
+    }
+}
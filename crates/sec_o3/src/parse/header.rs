@@ -0,0 +1,239 @@
+//! SEC headers of full submissions.
+//!
+//! Every full submission text file (and the `.hdr.sgml` file next to it)
+//! starts with a plain-text header naming the accession number, form type,
+//! dates, and each company involved:
+//!
+//! ```text
+//! <SEC-HEADER>0000320193-23-000106.hdr.sgml : 20231103
+//! <ACCEPTANCE-DATETIME>20231102180827
+//! ACCESSION NUMBER:       0000320193-23-000106
+//! CONFORMED SUBMISSION TYPE:  10-K
+//! FILED AS OF DATE:       20231103
+//!
+//! FILER:
+//!
+//!     COMPANY DATA:
+//!         COMPANY CONFORMED NAME:         Apple Inc.
+//!         CENTRAL INDEX KEY:          0000320193
+//! ```
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+/// A company named in an SEC header block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeaderCompany {
+    /// Conformed company name
+    pub name: Option<String>,
+    /// 10-digit Central Index Key
+    pub cik: Option<String>,
+}
+
+/// Fields of an SEC header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecHeader {
+    /// Accession number (e.g., "0000320193-23-000106")
+    pub accession_number: Option<String>,
+    /// Form type (e.g., "10-K")
+    pub form_type: Option<String>,
+    /// Date the filing was made
+    pub filing_date: Option<NaiveDate>,
+    /// End of the period the filing reports on
+    pub period_of_report: Option<NaiveDate>,
+    /// Acceptance time, as EDGAR's Eastern time labeled as UTC (like the submissions API)
+    pub acceptance_datetime: Option<DateTime<Utc>>,
+    /// Filing companies (`FILER`, `FILED BY`, and `REPORTING-OWNER` blocks), in header order
+    pub filers: Vec<HeaderCompany>,
+    /// Companies the filing is about (`SUBJECT COMPANY` and `ISSUER` blocks), in header order
+    pub subject_companies: Vec<HeaderCompany>,
+}
+
+impl SecHeader {
+    /// CIKs of all filers, in header order.
+    pub fn filer_ciks(&self) -> Vec<&str> {
+        self.filers.iter().filter_map(|filer| filer.cik.as_deref()).collect()
+    }
+}
+
+/// Parse the SEC header at the start of a full submission or `.hdr.sgml` file.
+///
+/// Reading stops at `</SEC-HEADER>` or the first `<DOCUMENT>`, so a whole
+/// submission can be passed in. The accession number and filing date fall
+/// back to the `<SEC-DOCUMENT>`/`<SEC-HEADER>` line when the header fields
+/// are missing. Fields that are absent or malformed are left empty.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::header::parse_sec_header;
+///
+/// let header = parse_sec_header(
+///     "<SEC-DOCUMENT>0000320193-23-000106.txt : 20231103\n\
+///      CONFORMED SUBMISSION TYPE:\t10-K\n\
+///      FILER:\n\
+///      \tCOMPANY DATA:\n\
+///      \t\tCOMPANY CONFORMED NAME:\t\t\tApple Inc.\n\
+///      \t\tCENTRAL INDEX KEY:\t\t\t0000320193\n",
+/// );
+///
+/// assert_eq!(header.accession_number.as_deref(), Some("0000320193-23-000106"));
+/// assert_eq!(header.filer_ciks(), ["0000320193"]);
+/// ```
+pub fn parse_sec_header(text: &str) -> SecHeader {
+    let mut header = SecHeader::default();
+    // Whether the current company block lists a filer or a subject company
+    let mut in_filer = None;
+
+    for line in text.lines().map(str::trim) {
+        if line.starts_with("</SEC-HEADER>") || line.starts_with("<DOCUMENT>") {
+            break;
+        }
+
+        if let Some(rest) = line
+            .strip_prefix("<SEC-DOCUMENT>")
+            .or_else(|| line.strip_prefix("<SEC-HEADER>"))
+        {
+            let (file, date) = rest.split_once(" : ").unwrap_or((rest, ""));
+            let accession = file.trim().split('.').next().unwrap_or_default();
+            if header.accession_number.is_none() && !accession.is_empty() {
+                header.accession_number = Some(accession.to_string());
+            }
+            header.filing_date = header.filing_date.or_else(|| parse_date(date));
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("<ACCEPTANCE-DATETIME>") {
+            header.acceptance_datetime = NaiveDateTime::parse_from_str(value.trim(), "%Y%m%d%H%M%S")
+                .ok()
+                .map(|accepted| accepted.and_utc());
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+
+        match key.trim() {
+            "ACCESSION NUMBER" if !value.is_empty() => header.accession_number = Some(value.to_string()),
+            "CONFORMED SUBMISSION TYPE" if !value.is_empty() => header.form_type = Some(value.to_string()),
+            "FILED AS OF DATE" => header.filing_date = parse_date(value).or(header.filing_date),
+            "CONFORMED PERIOD OF REPORT" => header.period_of_report = parse_date(value),
+            "FILER" | "FILED BY" | "REPORTING-OWNER" => {
+                header.filers.push(HeaderCompany::default());
+                in_filer = Some(true);
+            }
+            "SUBJECT COMPANY" | "ISSUER" => {
+                header.subject_companies.push(HeaderCompany::default());
+                in_filer = Some(false);
+            }
+            key @ ("COMPANY CONFORMED NAME" | "CENTRAL INDEX KEY") if !value.is_empty() => {
+                let company = match in_filer {
+                    Some(true) => header.filers.last_mut(),
+                    Some(false) => header.subject_companies.last_mut(),
+                    None => None,
+                };
+                if let Some(company) = company {
+                    if key == "CENTRAL INDEX KEY" {
+                        company.cik = Some(format!("{:0>10}", value));
+                    } else {
+                        company.name = Some(value.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    header
+}
+
+/// Parse a header date in YYYYMMDD format.
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y%m%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Co-registrant 10-K: a parent and its operating subsidiary file together
+    const TWO_FILERS: &str = "<SEC-DOCUMENT>0001193125-24-045789.txt : 20240223
+<SEC-HEADER>0001193125-24-045789.hdr.sgml : 20240223
+<ACCEPTANCE-DATETIME>20240223161502
+ACCESSION NUMBER:\t\t0001193125-24-045789
+CONFORMED SUBMISSION TYPE:\t10-K
+PUBLIC DOCUMENT COUNT:\t\t152
+CONFORMED PERIOD OF REPORT:\t20231231
+FILED AS OF DATE:\t\t20240223
+DATE AS OF CHANGE:\t\t20240223
+
+FILER:
+
+\tCOMPANY DATA:\t
+\t\tCOMPANY CONFORMED NAME:\t\t\tEXAMPLE ENERGY HOLDINGS INC
+\t\tCENTRAL INDEX KEY:\t\t\t0001711269
+\t\tSTANDARD INDUSTRIAL CLASSIFICATION:\tELECTRIC SERVICES [4911]
+
+\tFILING VALUES:
+\t\tFORM TYPE:\t\t10-K
+\t\tSEC FILE NUMBER:\t001-38126
+
+FILER:
+
+\tCOMPANY DATA:\t
+\t\tCOMPANY CONFORMED NAME:\t\t\tEXAMPLE ELECTRIC CO
+\t\tCENTRAL INDEX KEY:\t\t\t0000081033
+
+\tBUSINESS ADDRESS:\t
+\t\tCITY:\t\t\tHOUSTON
+</SEC-HEADER>
+<DOCUMENT>
+<TYPE>10-K
+CENTRAL INDEX KEY:\t0000999999
+</DOCUMENT>
+";
+
+    #[test]
+    fn test_parse_sec_header_with_two_filers() {
+        let header = parse_sec_header(TWO_FILERS);
+
+        assert_eq!(header.accession_number.as_deref(), Some("0001193125-24-045789"));
+        assert_eq!(header.form_type.as_deref(), Some("10-K"));
+        assert_eq!(header.filing_date, NaiveDate::from_ymd_opt(2024, 2, 23));
+        assert_eq!(header.period_of_report, NaiveDate::from_ymd_opt(2023, 12, 31));
+        assert_eq!(
+            header
+                .acceptance_datetime
+                .map(|accepted| accepted.to_rfc3339())
+                .as_deref(),
+            Some("2024-02-23T16:15:02+00:00")
+        );
+        assert_eq!(header.filer_ciks(), ["0001711269", "0000081033"]);
+        assert_eq!(header.filers[1].name.as_deref(), Some("EXAMPLE ELECTRIC CO"));
+        assert!(header.subject_companies.is_empty());
+    }
+
+    #[test]
+    fn test_parse_sec_header_subject_company_and_fallbacks() {
+        let header = parse_sec_header(
+            "<SEC-DOCUMENT>0000950170-23-012345.txt : 20230315
+CONFORMED SUBMISSION TYPE:\tSC 13D
+SUBJECT COMPANY:\t
+\tCOMPANY DATA:\t
+\t\tCOMPANY CONFORMED NAME:\t\t\tTARGET CORP
+\t\tCENTRAL INDEX KEY:\t\t\t27419
+FILED BY:\t\t
+\tCOMPANY DATA:\t
+\t\tCOMPANY CONFORMED NAME:\t\t\tACTIVIST FUND LP
+\t\tCENTRAL INDEX KEY:\t\t\t0001555555
+",
+        );
+
+        assert_eq!(header.accession_number.as_deref(), Some("0000950170-23-012345"));
+        assert_eq!(header.filing_date, NaiveDate::from_ymd_opt(2023, 3, 15));
+        assert_eq!(header.subject_companies[0].cik.as_deref(), Some("0000027419"));
+        assert_eq!(header.subject_companies[0].name.as_deref(), Some("TARGET CORP"));
+        assert_eq!(header.filer_ciks(), ["0001555555"]);
+        assert_eq!(header.acceptance_datetime, None);
+        assert_eq!(header.period_of_report, None);
+    }
+}
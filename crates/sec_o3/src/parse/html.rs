@@ -0,0 +1,664 @@
+//! HTML filing documents.
+//!
+//! Parses EDGAR HTML documents (10-K, 10-Q, 8-K, ...) into a [`Document`]
+//! holding the title, the detected form type, and the visible text with
+//! paragraph breaks preserved.
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::{Error, Result};
+
+/// Elements whose content is never visible text.
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style", "head", "title", "noscript"];
+
+/// Elements that end a line of text.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p", "div", "br", "tr", "li", "table", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "center",
+];
+
+/// Number of text characters searched for the form type.
+const FORM_TYPE_WINDOW: usize = 5_000;
+
+/// Number of leading bytes of the HTML read by [`parse_html_fast`].
+const FAST_PREFIX_LEN: usize = 64 * 1024;
+
+/// Longest text, in bytes, an element may have and still be a section heading.
+const MAX_HEADING_LEN: usize = 200;
+
+static FORM_TYPE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bform\s+(\d{1,2}-[a-z]{1,3}(?:/a)?)\b").unwrap());
+
+static TITLE_SELECTOR: Lazy<std::result::Result<Selector, String>> =
+    Lazy::new(|| Selector::parse("title").map_err(|e| e.to_string()));
+
+/// A `<title>` element, for scanning without building a DOM.
+static TITLE_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<title(?:\s[^>]*)?>(.*?)</title\s*>").unwrap());
+
+static ITEM_HEADING: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^item\s*(\d{1,2}[a-z]?)\b").unwrap());
+
+/// Exhibit document file names, e.g. "ex21.htm", "dex991.htm", "aapl-20230930xex311.htm", "exhibit10-1.htm".
+static EXHIBIT_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)(?:^|[^a-z]|[dx])ex(?:hibit)?[-_]?\d").unwrap());
+
+/// Link text naming an exhibit, e.g. "Exhibit 31.1".
+static EXHIBIT_LABEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^exhibit\s*\d").unwrap());
+
+/// The hidden `<ix:header>` block of an iXBRL document (contexts, units, hidden facts).
+static IX_HEADER: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)<ix:header\b.*?</ix:header\s*>").unwrap());
+
+/// An opening or closing `ix:` or `xbrli:` tag.
+static IX_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)</?(?:ix|xbrli):[a-z][^>]*>").unwrap());
+
+/// A parsed HTML filing document.
+///
+/// Two documents are equal when their text is the same after collapsing
+/// whitespace, regardless of title or markup (see [`Document::content_hash`]).
+#[derive(Debug, Clone)]
+pub struct Document {
+    /// Contents of the `<title>` element, trimmed
+    pub title: Option<String>,
+    /// Form type named near the top of the document (e.g., "10-K")
+    pub form_type: Option<String>,
+    /// Visible text, one line per block element
+    pub text: String,
+}
+
+impl Document {
+    /// Stable hash of the document text with whitespace normalized.
+    ///
+    /// Runs of whitespace are collapsed to a single space before hashing
+    /// with 64-bit FNV-1a, so the value is identical across platforms and
+    /// releases and can be persisted to detect mirrored or re-filed copies.
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = FNV_OFFSET;
+        for (i, word) in self.text.split_whitespace().enumerate() {
+            let separator: &[u8] = if i == 0 { b"" } else { b" " };
+            for &byte in separator.iter().chain(word.as_bytes()) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+}
+
+/// A hyperlink to an exhibit document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExhibitLink {
+    /// Absolute URL of the exhibit
+    pub url: String,
+    /// Link text with whitespace collapsed, or the file name if the link has no text
+    pub label: String,
+}
+
+impl PartialEq for Document {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_hash() == other.content_hash()
+    }
+}
+
+impl Eq for Document {}
+
+/// Parser for EDGAR HTML documents.
+pub struct HtmlParser;
+
+impl HtmlParser {
+    /// Parse an HTML document.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ParserError` if an internal selector fails to compile.
+    pub fn parse(html: &str) -> Result<Document> {
+        let document = Html::parse_document(html);
+
+        let title = document
+            .select(selector(&TITLE_SELECTOR)?)
+            .next()
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .filter(|title| !title.is_empty());
+
+        let mut text = String::new();
+        collect_text(document.root_element(), &mut text);
+        let text = normalize_lines(&text);
+
+        let form_type = detect_form_type(&text);
+
+        Ok(Document { title, form_type, text })
+    }
+}
+
+/// Parse an HTML document into a [`Document`].
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::html::parse_html;
+///
+/// let html = "<html><head><title>aapl-20230930</title></head>\
+///             <body><p>UNITED STATES</p><p>FORM 10-K</p></body></html>";
+///
+/// let doc = parse_html(html).unwrap();
+/// assert_eq!(doc.title.as_deref(), Some("aapl-20230930"));
+/// assert_eq!(doc.form_type.as_deref(), Some("10-K"));
+/// assert_eq!(doc.text, "UNITED STATES\nFORM 10-K");
+/// ```
+pub fn parse_html(html: &str) -> Result<Document> {
+    HtmlParser::parse(html)
+}
+
+/// Parse only the start of an HTML document, for fast classification.
+///
+/// The title is found by scanning for the `<title>` element instead of
+/// building a DOM, and the form type and text come from the first 64 KiB
+/// of the document. On documents shorter than that, the result matches
+/// [`parse_html`] except that only common character references are
+/// decoded in the title; on longer ones, [`Document::text`] holds only the
+/// text of the prefix.
+///
+/// # Errors
+///
+/// Returns `Error::ParserError` if an internal selector fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::html::parse_html_fast;
+///
+/// let html = format!(
+///     "<html><head><title>aapl-20230930</title></head><body><p>FORM 10-K</p>{}</body></html>",
+///     "<p>Net sales by category.</p>".repeat(10_000)
+/// );
+///
+/// let doc = parse_html_fast(&html).unwrap();
+/// assert_eq!(doc.title.as_deref(), Some("aapl-20230930"));
+/// assert_eq!(doc.form_type.as_deref(), Some("10-K"));
+/// assert!(doc.text.len() < html.len());
+/// ```
+pub fn parse_html_fast(html: &str) -> Result<Document> {
+    let mut end = html.len().min(FAST_PREFIX_LEN);
+    while !html.is_char_boundary(end) {
+        end -= 1;
+    }
+    let prefix = &html[..end];
+
+    let title = TITLE_TAG
+        .captures(prefix)
+        .map(|caps| decode_entities(caps[1].trim()).trim().to_string())
+        .filter(|title| !title.is_empty());
+
+    let document = Html::parse_document(prefix);
+    let mut text = String::new();
+    collect_text(document.root_element(), &mut text);
+    let text = normalize_lines(&text);
+
+    let form_type = detect_form_type(&text);
+
+    Ok(Document { title, form_type, text })
+}
+
+/// Build a [`Document`] from plain text, such as a `.txt` exhibit.
+///
+/// Whitespace is collapsed within lines and blank lines are dropped, as for
+/// HTML input. Plain text has no title.
+pub fn parse_text(text: &str) -> Document {
+    let text = normalize_lines(text);
+    let form_type = detect_form_type(&text);
+
+    Document {
+        title: None,
+        form_type,
+        text,
+    }
+}
+
+/// Visible text of an Inline XBRL document, without the XBRL markup.
+///
+/// The hidden `<ix:header>` block is dropped, and every other `ix:` or
+/// `xbrli:` element is unwrapped so tagged facts keep their displayed
+/// text in place. The rest is extracted as in [`parse_html`]: one line
+/// per block element, whitespace collapsed.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::html::strip_ixbrl_to_text;
+///
+/// let html = r#"<p>Net sales were $<ix:nonFraction name="us-gaap:Revenues" contextRef="c-1"
+///     unitRef="usd" decimals="-6" scale="6">383,285</ix:nonFraction> million.</p>"#;
+///
+/// assert_eq!(strip_ixbrl_to_text(html), "Net sales were $383,285 million.");
+/// ```
+pub fn strip_ixbrl_to_text(html: &str) -> String {
+    let html = IX_HEADER.replace_all(html, "");
+    let html = IX_TAG.replace_all(&html, "");
+
+    let document = Html::parse_document(&html);
+    let mut text = String::new();
+    collect_text(document.root_element(), &mut text);
+    normalize_lines(&text)
+}
+
+/// Extract the text of an item (e.g., "1A" or "Item 7") from an HTML filing.
+///
+/// Headings are found in the DOM: an element with short text that
+/// starts with "Item <n>", whether it is an `<h2>`, a `<b>`, or a styled
+/// `<p>`. The section is the text after the heading up to the next item
+/// heading. Since the table of contents repeats every heading, the longest
+/// matching section is returned.
+///
+/// Returns `None` if no heading matches or the section has no text.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::html::extract_section_html;
+///
+/// let html = "<html><body><p><b>Item 1A. Risk Factors</b></p><p>Competition is intense.</p>\
+///             <p><b>Item 1B. Unresolved Staff Comments</b></p><p>None.</p></body></html>";
+///
+/// assert_eq!(extract_section_html(html, "1A").as_deref(), Some("Competition is intense."));
+/// ```
+pub fn extract_section_html(html: &str, item: &str) -> Option<String> {
+    let wanted = item.trim();
+    let wanted = if wanted
+        .get(..4)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("item"))
+    {
+        &wanted[4..]
+    } else {
+        wanted
+    };
+    let wanted = wanted.trim().trim_end_matches('.').to_ascii_uppercase();
+
+    let document = Html::parse_document(html);
+    let mut sections = vec![(None, String::new())];
+    collect_sections(document.root_element(), &mut sections);
+
+    sections
+        .into_iter()
+        .filter(|(heading, _)| heading.as_deref() == Some(wanted.as_str()))
+        .map(|(_, text)| normalize_lines(&text))
+        .filter(|text| !text.is_empty())
+        .max_by_key(String::len)
+}
+
+/// List the exhibit documents hyperlinked from an HTML filing.
+///
+/// A link is an exhibit when its file name looks like one ("ex21.htm",
+/// "dex991.htm", "aapl-20230930xex311.htm") or its text starts with
+/// "Exhibit". Targets are resolved against `base_url`, usually the
+/// document's own URL, and each URL is listed once, in document order.
+///
+/// Returns an empty list if `base_url` is not an absolute URL.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::html::extract_exhibit_links;
+///
+/// let html = r#"<html><body><a href="ex211.htm">Subsidiaries of the Registrant</a></body></html>"#;
+/// let base = "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm";
+///
+/// let links = extract_exhibit_links(html, base);
+/// assert_eq!(links[0].url, "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/ex211.htm");
+/// assert_eq!(links[0].label, "Subsidiaries of the Registrant");
+/// ```
+pub fn extract_exhibit_links(html: &str, base_url: &str) -> Vec<ExhibitLink> {
+    let Ok(base) = url::Url::parse(base_url) else {
+        return Vec::new();
+    };
+
+    let document = Html::parse_document(html);
+    let mut links: Vec<ExhibitLink> = Vec::new();
+
+    for anchor in document.root_element().descendants().filter_map(ElementRef::wrap) {
+        if anchor.value().name() != "a" {
+            continue;
+        }
+        let Some(href) = anchor.value().attr("href").map(str::trim) else {
+            continue;
+        };
+        let Ok(mut url) = base.join(href) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            continue;
+        }
+        url.set_fragment(None);
+
+        let file_name = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .unwrap_or("");
+        let text = anchor.text().collect::<String>();
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let is_index = file_name.to_ascii_lowercase().contains("index");
+        let is_exhibit = EXHIBIT_LABEL.is_match(&text) || (!is_index && EXHIBIT_FILE.is_match(file_name));
+        if !is_exhibit || links.iter().any(|link| link.url == url.as_str()) {
+            continue;
+        }
+
+        let label = if text.is_empty() { file_name.to_string() } else { text };
+        links.push(ExhibitLink { url: url.into(), label });
+    }
+
+    links
+}
+
+/// Append the visible text under `element`, breaking lines after block elements.
+fn collect_text(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => push_text(out, text),
+            Node::Element(el) if SKIPPED_ELEMENTS.contains(&el.name()) => {}
+            Node::Element(el) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    collect_text(child, out);
+                }
+                push_break(out, el.name());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Like [`collect_text`], but starts a new `(item, text)` section at each item heading.
+fn collect_sections(element: ElementRef, sections: &mut Vec<(Option<String>, String)>) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => {
+                if let Some((_, out)) = sections.last_mut() {
+                    push_text(out, text);
+                }
+            }
+            Node::Element(el) if SKIPPED_ELEMENTS.contains(&el.name()) => {}
+            Node::Element(el) => {
+                let Some(child) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                if let Some(item) = item_heading(child) {
+                    sections.push((Some(item), String::new()));
+                    continue;
+                }
+
+                collect_sections(child, sections);
+                if let Some((_, out)) = sections.last_mut() {
+                    push_break(out, el.name());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Item number if `element` is an item heading.
+fn item_heading(element: ElementRef) -> Option<String> {
+    if matches!(element.value().name(), "html" | "body") {
+        return None;
+    }
+
+    let mut text = String::new();
+    for chunk in element.text() {
+        text.push_str(chunk);
+        if text.len() > MAX_HEADING_LEN {
+            return None;
+        }
+    }
+
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let item = ITEM_HEADING.captures(&collapsed)?[1].to_ascii_uppercase();
+
+    // A short block holding both the heading and its paragraphs is walked
+    // into instead, so the heading's own element is found
+    let has_block_child = element.children().filter_map(ElementRef::wrap).any(|child| {
+        let name = child.value().name();
+        name != "br" && name != "hr" && BLOCK_ELEMENTS.contains(&name)
+    });
+    if has_block_child {
+        return None;
+    }
+
+    Some(item)
+}
+
+fn push_text(out: &mut String, text: &str) {
+    // Source line breaks are insignificant whitespace in HTML
+    out.extend(text.chars().map(|c| if c == '\n' || c == '\r' { ' ' } else { c }));
+}
+
+fn push_break(out: &mut String, element: &str) {
+    if BLOCK_ELEMENTS.contains(&element) {
+        out.push('\n');
+    } else if matches!(element, "td" | "th") {
+        out.push(' ');
+    }
+}
+
+/// Decode the character references most common in titles.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    text.replace("&nbsp;", "\u{a0}")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Borrow a cached selector, reporting a compile failure as an error.
+fn selector(cached: &'static Lazy<std::result::Result<Selector, String>>) -> Result<&'static Selector> {
+    Lazy::force(cached)
+        .as_ref()
+        .map_err(|e| Error::ParserError(format!("Invalid selector: {}", e)))
+}
+
+/// Collapse whitespace within lines and drop empty lines.
+fn normalize_lines(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Find the form type named near the start of the text (e.g., "FORM 10-K").
+fn detect_form_type(text: &str) -> Option<String> {
+    let mut end = text.len().min(FORM_TYPE_WINDOW);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    FORM_TYPE
+        .captures(&text[..end])
+        .map(|caps| caps[1].to_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_retains_text_and_skips_scripts() {
+        let html = r#"<html><head><title> Form 8-K </title><style>p { color: red }</style></head>
+            <body><div>FORM 8-K</div><script>var x = 1;</script>
+            <p>Item 2.02   Results of
+               Operations</p><table><tr><td>Revenue</td><td>$1,000</td></tr></table></body></html>"#;
+
+        let doc = parse_html(html).unwrap();
+        assert_eq!(doc.title.as_deref(), Some("Form 8-K"));
+        assert_eq!(doc.form_type.as_deref(), Some("8-K"));
+        assert_eq!(doc.text, "FORM 8-K\nItem 2.02 Results of Operations\nRevenue $1,000");
+    }
+
+    #[test]
+    fn test_whitespace_different_copies_hash_equal() {
+        let original =
+            parse_html("<html><body><p>FORM 10-K</p><p>Annual report for fiscal 2023.</p></body></html>").unwrap();
+        let mirrored = parse_html(
+            "<html>\n<head><title>mirror</title></head>\n<body>\n  <div>FORM   10-K</div>\n\n  <div>Annual report\n    for fiscal 2023.</div>\n</body></html>",
+        )
+        .unwrap();
+        let amended =
+            parse_html("<html><body><p>FORM 10-K/A</p><p>Annual report for fiscal 2023.</p></body></html>").unwrap();
+
+        assert_eq!(original.content_hash(), mirrored.content_hash());
+        assert_eq!(original, mirrored);
+        assert_ne!(original, amended);
+    }
+
+    #[test]
+    fn test_extract_section_html_bold_headings() {
+        let html = r##"<html><body>
+            <table>
+              <tr><td><a href="#i1a">Item 1A.</a></td><td>Risk Factors</td><td>12</td></tr>
+              <tr><td><a href="#i1b">Item 1B.</a></td><td>Unresolved Staff Comments</td><td>20</td></tr>
+            </table>
+            <div><span>PART I</span></div>
+            <div id="i1a"><b>Item 1A.&nbsp;&nbsp;&nbsp;Risk Factors</b></div>
+            <p>The Company's business can be affected by
+               many factors.</p>
+            <p><i>Macroeconomic Risks</i></p>
+            <div>Adverse economic conditions could reduce demand.</div>
+            <p id="i1b"><b>ITEM 1B. </b><b>Unresolved Staff Comments</b></p>
+            <p>None.</p>
+            <h2>Item 2. Properties</h2>
+            <p>Cupertino, California.</p>
+        </body></html>"##;
+
+        assert_eq!(
+            extract_section_html(html, "Item 1A").as_deref(),
+            Some("The Company's business can be affected by many factors.\nMacroeconomic Risks\nAdverse economic conditions could reduce demand.")
+        );
+        assert_eq!(extract_section_html(html, "1b.").as_deref(), Some("None."));
+        assert_eq!(
+            extract_section_html(html, "2").as_deref(),
+            Some("Cupertino, California.")
+        );
+        assert_eq!(extract_section_html(html, "7"), None);
+    }
+
+    #[test]
+    fn test_extract_section_html_multibyte_item() {
+        let html = "<html><body><p><b>Item 1A. Risk Factors</b></p><p>Text.</p></body></html>";
+
+        // A multi-byte character straddling byte 4 must not panic
+        assert_eq!(extract_section_html(html, "12€"), None);
+        assert_eq!(extract_section_html(html, "€€"), None);
+    }
+
+    #[test]
+    fn test_cached_selector_parses_repeatedly() {
+        assert!(selector(&TITLE_SELECTOR).is_ok());
+
+        let html = "<html><head><title>aapl-20230930</title></head><body><p>FORM 10-K</p></body></html>";
+        let first = parse_html(html).unwrap();
+        for _ in 0..100 {
+            let doc = parse_html(html).unwrap();
+            assert_eq!(doc.title, first.title);
+            assert_eq!(doc.text, first.text);
+        }
+        assert_eq!(first.title.as_deref(), Some("aapl-20230930"));
+        assert_eq!(parse_html("<p>untitled</p>").unwrap().title, None);
+    }
+
+    #[test]
+    fn test_parse_html_fast_matches_parse_html_on_small_doc() {
+        let html = r#"<html><head><TITLE class="x"> Apple Inc. &amp; Subsidiaries - 10-K </TITLE></head>
+            <body><div>UNITED STATES</div><p>FORM 10-K</p><script>var x = 1;</script>
+            <p>Annual report for fiscal 2023.</p></body></html>"#;
+
+        let fast = parse_html_fast(html).unwrap();
+        let full = parse_html(html).unwrap();
+
+        assert_eq!(fast.title, full.title);
+        assert_eq!(fast.title.as_deref(), Some("Apple Inc. & Subsidiaries - 10-K"));
+        assert_eq!(fast.form_type, full.form_type);
+        assert_eq!(fast.text, full.text);
+
+        assert_eq!(parse_html_fast("<p>untitled</p>").unwrap().title, None);
+    }
+
+    #[test]
+    fn test_parse_html_fast_reads_bounded_prefix() {
+        let html = format!(
+            "<html><head><title>big</title></head><body><p>FORM 10-Q</p>{}<p>FORM 8-K tail</p></body></html>",
+            "<p>filler paragraph</p>".repeat(10_000)
+        );
+
+        let doc = parse_html_fast(&html).unwrap();
+
+        assert_eq!(doc.form_type.as_deref(), Some("10-Q"));
+        assert!(doc.text.len() < FAST_PREFIX_LEN);
+        assert!(!doc.text.contains("tail"));
+    }
+
+    #[test]
+    fn test_extract_exhibit_links() {
+        let html = r##"<html><body>
+            <p><a href="#toc">Table of Contents</a></p>
+            <table>
+              <tr><td>21.1</td><td><a href="aapl-20230930xex211.htm">Subsidiaries of
+                  the Registrant</a></td></tr>
+              <tr><td>31.1</td><td><a href="/Archives/edgar/data/320193/000032019323000106/aapl-20230930xex311.htm">Exhibit 31.1</a></td></tr>
+              <tr><td>3.1</td><td><a href="https://www.sec.gov/Archives/edgar/data/320193/000119312520001234/d123dex31.htm">Restated Articles</a></td></tr>
+              <tr><td>31.1</td><td><a href="aapl-20230930xex311.htm#page2">Certification</a></td></tr>
+            </table>
+            <a href="0000320193-23-000106-index.htm">Filing index</a>
+            <a href="mailto:ir@example.com">Exhibit 99 contact</a>
+            </body></html>"##;
+        let base = "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm";
+
+        let links = extract_exhibit_links(html, base);
+
+        let dir = "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106";
+        assert_eq!(
+            links,
+            vec![
+                ExhibitLink {
+                    url: format!("{}/aapl-20230930xex211.htm", dir),
+                    label: "Subsidiaries of the Registrant".to_string(),
+                },
+                ExhibitLink {
+                    url: format!("{}/aapl-20230930xex311.htm", dir),
+                    label: "Exhibit 31.1".to_string(),
+                },
+                ExhibitLink {
+                    url: "https://www.sec.gov/Archives/edgar/data/320193/000119312520001234/d123dex31.htm".to_string(),
+                    label: "Restated Articles".to_string(),
+                },
+            ]
+        );
+
+        assert!(extract_exhibit_links(html, "not a url").is_empty());
+    }
+
+    #[test]
+    fn test_strip_ixbrl_to_text() {
+        let html = r#"<html xmlns:ix="http://www.xbrl.org/2013/inlineXBRL"><body>
+<div style="display:none"><ix:header><ix:hidden>
+<ix:nonNumeric name="dei:AmendmentFlag" contextRef="c-1">false</ix:nonNumeric>
+</ix:hidden><ix:resources><xbrli:context id="c-1"><xbrli:entity>
+<xbrli:identifier scheme="http://www.sec.gov/CIK">0000320193</xbrli:identifier>
+</xbrli:entity></xbrli:context></ix:resources></ix:header></div>
+<p>Total net sales were $<ix:nonFraction unitRef="usd" contextRef="c-1" decimals="-6"
+ name="us-gaap:RevenueFromContractWithCustomerExcludingAssessedTax" format="ixt:num-dot-decimal"
+ scale="6">383,285</ix:nonFraction> million.</p>
+<table><tr><td>Net income</td><td><ix:nonFraction name="us-gaap:NetIncomeLoss" contextRef="c-1"
+ unitRef="usd">96,995</ix:nonFraction></td></tr></table>
+</body></html>"#;
+
+        let text = strip_ixbrl_to_text(html);
+
+        assert_eq!(text, "Total net sales were $383,285 million.\nNet income 96,995");
+        assert!(!text.contains("nonFraction"));
+        assert!(!text.contains("0000320193"));
+        assert!(!text.contains("false"));
+    }
+}
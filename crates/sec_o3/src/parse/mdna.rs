@@ -0,0 +1,224 @@
+//! Management's Discussion and Analysis (Item 7) extraction.
+//!
+//! Item 7 of a 10-K usually opens with an overview and then discusses
+//! results of operations and liquidity under their own sub-headings. This
+//! module finds Item 7 in the text of a filing and splits it along those
+//! sub-headings.
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Longest line, in characters, treated as a sub-heading.
+const MAX_HEADING_LEN: usize = 100;
+
+static ITEM_7: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^item\s*7(?:[^0-9a-z]|$)").unwrap());
+
+/// Headings that end Item 7: Item 7A (market risk) or Item 8 (financial statements).
+static NEXT_ITEM: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^item\s*(?:7a|8)(?:[^0-9a-z]|$)").unwrap());
+
+static OVERVIEW: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^(?:business\s+|executive\s+)?overview\b").unwrap());
+
+static RESULTS: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^results\s+of\s+operations\b").unwrap());
+
+static LIQUIDITY: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^liquidity\b").unwrap());
+
+/// The MD&A section of a filing, split into its common subsections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mdna {
+    /// Overview or executive summary; the whole section if no sub-headings were found
+    pub overview: Option<String>,
+    /// Results of operations discussion
+    pub results_of_operations: Option<String>,
+    /// Liquidity and capital resources discussion
+    pub liquidity: Option<String>,
+    /// Full text of Item 7, without its heading
+    pub text: String,
+}
+
+/// Subsection a sub-heading starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Subsection {
+    Overview,
+    Results,
+    Liquidity,
+}
+
+/// Extract Item 7 (MD&A) from the text of a 10-K and split it into subsections.
+///
+/// Item 7 runs from an "Item 7" heading line to the next "Item 7A" or
+/// "Item 8" heading. Since the table of contents repeats the headings, the
+/// longest such section is used. Within it, a short line starting with
+/// "Overview", "Results of Operations", or "Liquidity" starts the
+/// corresponding subsection; only the first heading of each kind counts.
+/// Text before the first sub-heading is kept only in [`Mdna::text`].
+///
+/// If none of the sub-headings are found, the whole section is returned
+/// as the overview. Returns `None` if the text has no Item 7.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::mdna::extract_mdna;
+///
+/// let text = "Item 7. Management's Discussion and Analysis\n\
+///             Overview\nRevenue grew.\n\
+///             Liquidity and Capital Resources\nWe hold $10 billion in cash.\n\
+///             Item 8. Financial Statements";
+///
+/// let mdna = extract_mdna(text).unwrap();
+/// assert_eq!(mdna.overview.as_deref(), Some("Revenue grew."));
+/// assert_eq!(mdna.liquidity.as_deref(), Some("We hold $10 billion in cash."));
+/// assert_eq!(mdna.results_of_operations, None);
+/// ```
+pub fn extract_mdna(text: &str) -> Option<Mdna> {
+    let lines = item_7_lines(text)?;
+    let body = lines.join("\n").trim().to_string();
+
+    let mut sections: Vec<(Subsection, Vec<&str>)> = Vec::new();
+    for line in lines {
+        let started = sections.iter().map(|(kind, _)| *kind).collect::<Vec<_>>();
+        match subsection_heading(line).filter(|kind| !started.contains(kind)) {
+            Some(kind) => sections.push((kind, Vec::new())),
+            None => {
+                if let Some((_, section)) = sections.last_mut() {
+                    section.push(line);
+                }
+            }
+        }
+    }
+
+    if sections.is_empty() {
+        return Some(Mdna {
+            overview: Some(body.clone()).filter(|body| !body.is_empty()),
+            results_of_operations: None,
+            liquidity: None,
+            text: body,
+        });
+    }
+
+    let section = |wanted: Subsection| {
+        sections
+            .iter()
+            .find(|(kind, _)| *kind == wanted)
+            .map(|(_, lines)| lines.join("\n").trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    Some(Mdna {
+        overview: section(Subsection::Overview),
+        results_of_operations: section(Subsection::Results),
+        liquidity: section(Subsection::Liquidity),
+        text: body,
+    })
+}
+
+/// Lines of the longest Item 7 section, excluding its heading.
+fn item_7_lines(text: &str) -> Option<Vec<&str>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut best: Option<&[&str]> = None;
+
+    for (start, line) in lines.iter().enumerate() {
+        if !ITEM_7.is_match(line.trim()) {
+            continue;
+        }
+
+        let body = &lines[start + 1..];
+        let end = body
+            .iter()
+            .position(|line| {
+                let line = line.trim();
+                NEXT_ITEM.is_match(line) || ITEM_7.is_match(line)
+            })
+            .unwrap_or(body.len());
+        let section = &body[..end];
+
+        let len = |lines: &[&str]| lines.iter().map(|line| line.trim().len()).sum::<usize>();
+        if best.is_none_or(|best| len(section) > len(best)) {
+            best = Some(section);
+        }
+    }
+
+    best.map(<[&str]>::to_vec)
+}
+
+/// Subsection started by `line`, if it is a recognized sub-heading.
+fn subsection_heading(line: &str) -> Option<Subsection> {
+    let line = line.trim();
+    if line.chars().count() > MAX_HEADING_LEN || line.ends_with(['.', ',', ';']) {
+        return None;
+    }
+
+    if OVERVIEW.is_match(line) {
+        Some(Subsection::Overview)
+    } else if RESULTS.is_match(line) {
+        Some(Subsection::Results)
+    } else if LIQUIDITY.is_match(line) {
+        Some(Subsection::Liquidity)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILING: &str = "\
+Table of Contents
+Item 7. Management's Discussion and Analysis of Financial Condition and Results of Operations 20
+Item 7A. Quantitative and Qualitative Disclosures About Market Risk 35
+Item 8. Financial Statements and Supplementary Data 36
+
+Item 7. Management's Discussion and Analysis of Financial Condition and Results of Operations
+
+The following discussion should be read together with the consolidated financial statements.
+
+Business Overview
+We design and sell consumer electronics worldwide.
+Fiscal 2023 was marked by supply constraints.
+
+Results of Operations
+Net sales decreased 3% to $383.3 billion.
+Gross margin was 44.1%.
+
+Liquidity and Capital Resources
+We believe our cash balances are sufficient for the next 12 months.
+Overview of cash flows is provided below.
+
+Item 7A. Quantitative and Qualitative Disclosures About Market Risk
+Interest rate risk.";
+
+    #[test]
+    fn test_extract_mdna_subsections() {
+        let mdna = extract_mdna(FILING).unwrap();
+
+        assert_eq!(
+            mdna.overview.as_deref(),
+            Some("We design and sell consumer electronics worldwide.\nFiscal 2023 was marked by supply constraints.")
+        );
+        assert_eq!(
+            mdna.results_of_operations.as_deref(),
+            Some("Net sales decreased 3% to $383.3 billion.\nGross margin was 44.1%.")
+        );
+        assert_eq!(
+            mdna.liquidity.as_deref(),
+            Some(
+                "We believe our cash balances are sufficient for the next 12 months.\n\
+                 Overview of cash flows is provided below."
+            )
+        );
+        assert!(mdna.text.starts_with("The following discussion"));
+        assert!(!mdna.text.contains("Interest rate risk"));
+    }
+
+    #[test]
+    fn test_extract_mdna_without_subheadings() {
+        let text = "Item 7. MD&A\nSales grew in every segment.\nItem 8. Financial Statements";
+
+        let mdna = extract_mdna(text).unwrap();
+        assert_eq!(mdna.overview.as_deref(), Some("Sales grew in every segment."));
+        assert_eq!(mdna.results_of_operations, None);
+        assert_eq!(mdna.liquidity, None);
+
+        assert_eq!(extract_mdna("Item 1. Business\nWe sell things."), None);
+    }
+}
@@ -0,0 +1,50 @@
+//! Parsers for SEC filing documents.
+//!
+//! This module contains parsers that operate on already-downloaded
+//! content (XBRL instances, HTML, and full submission text) and turn
+//! it into typed Rust structures.
+//!
+//! # Submodules
+//!
+//! * [`auditor`] - Audit firm extraction from annual reports
+//! * [`chunk`] - Size-bounded text chunks for NLP models
+//! * [`datasets`] - SEC structured dataset files (insider transactions)
+//! * [`filing_summary`] - Typed parser for `FilingSummary.xml`
+//! * [`header`] - SEC headers of full submissions
+//! * [`html`] - HTML filing documents
+//! * [`mdna`] - MD&A (Item 7) extraction and subsections
+//! * [`ndjson`] - Filing records from newline-delimited JSON
+//! * [`numbers`] - Monetary amounts in filing text
+//! * [`risk`] - Risk factor extraction from Item 1A
+//! * [`submission`] - Documents within full submission text files
+//! * [`xbrl`] - Streaming parser for XBRL instance documents
+pub mod auditor;
+pub mod chunk;
+pub mod datasets;
+pub mod filing_summary;
+pub mod header;
+pub mod html;
+pub mod mdna;
+pub mod ndjson;
+pub mod numbers;
+pub mod risk;
+pub mod submission;
+pub mod xbrl;
+
+pub use submission::{split_documents, submission_documents, submission_manifest, DocManifestEntry, SubmissionDoc};
+
+use quick_xml::events::BytesRef;
+
+use crate::{Error, Result};
+
+/// Resolve an XML character or predefined entity reference (e.g., `&amp;`, `&#39;`).
+pub(crate) fn resolve_xml_entity(entity: &BytesRef) -> Result<String> {
+    if let Some(ch) = entity.resolve_char_ref().map_err(|e| Error::XmlError(e.to_string()))? {
+        return Ok(ch.to_string());
+    }
+
+    let name = entity.decode().map_err(|e| Error::XmlError(e.to_string()))?;
+    quick_xml::escape::resolve_predefined_entity(&name)
+        .map(str::to_string)
+        .ok_or_else(|| Error::XmlError(format!("Unknown entity: &{};", name)))
+}
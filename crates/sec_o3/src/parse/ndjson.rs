@@ -0,0 +1,151 @@
+//! Newline-delimited JSON (NDJSON) filing records.
+//!
+//! Ingestion pipelines often pass filings between stages as one JSON
+//! object per line. This module reads such streams lazily, so a malformed
+//! line is reported on its own instead of aborting the whole file.
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::io::BufRead;
+
+use crate::filings::Filing;
+use crate::utils::str_to_utc_datetime;
+use crate::{Error, Result};
+
+/// One filing as written in an NDJSON line.
+///
+/// Field names match [`Filing`]; camelCase names as used by the
+/// submissions API (e.g., `accessionNumber`) are accepted as well.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FilingRecord {
+    /// Company's Central Index Key
+    pub cik: String,
+    /// Accession number (e.g., "0000320193-23-000106")
+    #[serde(alias = "accessionNumber")]
+    pub accession_number: String,
+    /// Form type (e.g., "10-K")
+    #[serde(alias = "form", alias = "formType")]
+    pub form_type: String,
+    /// Acceptance time as `YYYY-MM-DDTHH:MM:SS.sssZ` or `YYYY-MM-DD`
+    #[serde(alias = "acceptanceDateTime", alias = "acceptanceDate")]
+    pub acceptance_date: String,
+    /// Primary document file name
+    #[serde(default, alias = "primaryDocument")]
+    pub primary_document: String,
+    /// Whether the filing contains XBRL data
+    #[serde(default, alias = "isXBRL")]
+    pub is_xbrl: bool,
+    /// Filing size in bytes
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// End of the reported period as `YYYY-MM-DD`; empty when not applicable
+    #[serde(default, alias = "reportDate")]
+    pub report_date: Option<String>,
+}
+
+impl FilingRecord {
+    /// Convert to a [`Filing`], parsing the acceptance time and report date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` if the acceptance time is not in a supported
+    /// format, or the report date is neither empty nor `YYYY-MM-DD`.
+    pub fn into_filing(self) -> Result<Filing> {
+        let report_date = match self.report_date.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(date) => Some(
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .map_err(|e| Error::Custom(format!("Invalid report date '{}': {}", date, e)))?,
+            ),
+        };
+
+        Ok(Filing {
+            acceptance_date: str_to_utc_datetime(&self.acceptance_date)?,
+            cik: self.cik,
+            accession_number: self.accession_number,
+            form_type: self.form_type,
+            primary_document: self.primary_document,
+            is_xbrl: self.is_xbrl,
+            size: self.size,
+            report_date,
+        })
+    }
+}
+
+/// Read filing records from NDJSON, one object per line.
+///
+/// Lines are parsed lazily as the iterator advances. Blank lines are
+/// skipped. A line that is not a valid record yields an `Error::Custom`
+/// naming its (1-based) line number, and reading continues with the next
+/// line; a read failure yields an `Error::IoError`.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::ndjson::read_filings;
+///
+/// let ndjson = r#"{"cik":"320193","accession_number":"0000320193-23-000106","form_type":"10-K","acceptance_date":"2023-11-03"}
+///
+/// not json
+/// "#;
+///
+/// let records: Vec<_> = read_filings(ndjson.as_bytes()).collect();
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].as_ref().unwrap().form_type, "10-K");
+/// assert!(records[1].is_err());
+/// ```
+pub fn read_filings(reader: impl BufRead) -> impl Iterator<Item = Result<FilingRecord>> {
+    reader.lines().enumerate().filter_map(|(index, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(Error::IoError(e))),
+        };
+        if line.trim().is_empty() {
+            return None;
+        }
+
+        Some(
+            serde_json::from_str(&line)
+                .map_err(|e| Error::Custom(format!("Invalid filing record on line {}: {}", index + 1, e))),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_filings_skips_blank_and_reports_malformed_lines() {
+        let ndjson = concat!(
+            r#"{"cik":"320193","accession_number":"0000320193-23-000106","form_type":"10-K","acceptance_date":"2023-11-03T18:01:14.000Z","primary_document":"aapl-20230930.htm","is_xbrl":true,"size":9876543}"#,
+            "\n\n",
+            r#"{"cik":"320193","accession_number":"0000320193-23-000077","form_type":"#,
+            "\n   \n",
+            r#"{"cik":"789019","accessionNumber":"0000950170-23-035122","form":"10-K","acceptanceDateTime":"2023-07-27","reportDate":"2023-06-30"}"#,
+            "\n",
+        );
+
+        let records: Vec<Result<FilingRecord>> = read_filings(ndjson.as_bytes()).collect();
+
+        assert_eq!(records.len(), 3);
+
+        let apple = records[0].as_ref().unwrap();
+        assert_eq!(apple.primary_document, "aapl-20230930.htm");
+        assert!(apple.is_xbrl);
+        assert_eq!(apple.size, Some(9_876_543));
+
+        let err = records[1].as_ref().unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+
+        let microsoft = records[2].as_ref().unwrap().clone();
+        assert_eq!(microsoft.accession_number, "0000950170-23-035122");
+        assert!(!microsoft.is_xbrl);
+
+        let filing = microsoft.into_filing().unwrap();
+        assert_eq!(filing.acceptance_date.to_rfc3339(), "2023-07-27T00:00:00+00:00");
+        assert_eq!(filing.report_date, NaiveDate::from_ymd_opt(2023, 6, 30));
+
+        let apple = records[0].as_ref().unwrap().clone().into_filing().unwrap();
+        assert_eq!(apple.report_date, None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Monetary amounts in filing text.
+//!
+//! Filings write amounts in many shapes: "$1.2 billion" in prose,
+//! "(450)" for a negative table cell, "1,234 thousand" next to a scale
+//! note. [`parse_money`] normalizes them to a plain value.
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Matches one amount, with optional currency, sign, scale word, and parentheses.
+static MONEY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^\s*(?P<open>\()?\s*(?P<sign>[-\u{2212}])?\s*(?P<symbol>[$€£¥]|[A-Z]{3}\b)?\s*(?P<inner_open>\()?\s*(?P<inner_sign>[-\u{2212}])?\s*(?P<number>\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:\.\d+)?|\.\d+)\s*(?P<inner_close>\))?\s*(?P<scale>(?i:thousands?|millions?|billions?|trillions?))?\s*(?P<code>[A-Z]{3})?\s*(?P<close>\))?\s*$",
+    )
+    .unwrap()
+});
+
+/// A monetary amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Money {
+    /// Amount in whole currency units, with any scale word applied
+    pub value: f64,
+    /// ISO 4217 currency code, when a symbol or code is given
+    pub currency: Option<String>,
+}
+
+/// Parse a monetary amount such as "$1.2 billion", "(450)", or "1,234 thousand".
+///
+/// Handles thousands separators, a leading minus or enclosing parentheses
+/// as negative, the scale words thousand, million, billion, and trillion
+/// (any case, singular or plural), and a currency symbol (`$`, `€`, `£`,
+/// `¥`) or three-letter code before or after the number. Returns `None`
+/// when the whole string is not a single amount.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::numbers::parse_money;
+///
+/// let money = parse_money("$1.2 billion").unwrap();
+/// assert_eq!(money.value, 1_200_000_000.0);
+/// assert_eq!(money.currency.as_deref(), Some("USD"));
+///
+/// assert_eq!(parse_money("(450)").unwrap().value, -450.0);
+/// assert_eq!(parse_money("1,234 thousand").unwrap().value, 1_234_000.0);
+/// ```
+pub fn parse_money(s: &str) -> Option<Money> {
+    let caps = MONEY.captures(s)?;
+    // "(450)" can match either pair of parentheses; only the counts must agree
+    let count = |names: [&str; 2]| names.iter().filter(|name| caps.name(name).is_some()).count();
+    let parens = count(["open", "inner_open"]);
+    if parens != count(["close", "inner_close"]) {
+        return None;
+    }
+
+    let currency = match (caps.name("symbol"), caps.name("code")) {
+        (Some(_), Some(_)) => return None,
+        (Some(symbol), None) => Some(match symbol.as_str() {
+            "$" => "USD",
+            "€" => "EUR",
+            "£" => "GBP",
+            "¥" => "JPY",
+            code => code,
+        }),
+        (None, code) => code.map(|code| code.as_str()),
+    };
+
+    let exponent = match caps.name("scale").map(|scale| scale.as_str().to_ascii_lowercase()) {
+        Some(scale) if scale.starts_with("thousand") => 3,
+        Some(scale) if scale.starts_with("million") => 6,
+        Some(scale) if scale.starts_with("billion") => 9,
+        Some(_) => 12,
+        None => 0,
+    };
+    // Scaling through the exponent keeps "1.2 billion" exactly 1.2e9
+    let digits = caps["number"].replace(',', "");
+    let mut value: f64 = format!("{}e{}", digits, exponent).parse().ok()?;
+
+    let negative = parens > 0 || count(["sign", "inner_sign"]) > 0;
+    if negative {
+        value = -value;
+    }
+
+    Some(Money {
+        value,
+        currency: currency.map(str::to_string),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(s: &str) -> Option<f64> {
+        parse_money(s).map(|money| money.value)
+    }
+
+    #[test]
+    fn test_parse_money_forms() {
+        assert_eq!(value("1,234,567"), Some(1_234_567.0));
+        assert_eq!(value("383.3"), Some(383.3));
+        assert_eq!(value("(450)"), Some(-450.0));
+        assert_eq!(value("$(1,200)"), Some(-1_200.0));
+        assert_eq!(value("($3.5 million)"), Some(-3_500_000.0));
+        assert_eq!(value("-12"), Some(-12.0));
+        assert_eq!(value("\u{2212}12"), Some(-12.0));
+        assert_eq!(value("1,234 thousand"), Some(1_234_000.0));
+        assert_eq!(value("$1.2 billion"), Some(1_200_000_000.0));
+        assert_eq!(value("2 Million"), Some(2_000_000.0));
+        assert_eq!(value("$0.5 trillion"), Some(500_000_000_000.0));
+    }
+
+    #[test]
+    fn test_parse_money_currency() {
+        let currency = |s: &str| parse_money(s).and_then(|money| money.currency);
+
+        assert_eq!(currency("$10"), Some("USD".to_string()));
+        assert_eq!(currency("€2.5 million"), Some("EUR".to_string()));
+        assert_eq!(currency("£(40)"), Some("GBP".to_string()));
+        assert_eq!(currency("USD 1,000"), Some("USD".to_string()));
+        assert_eq!(currency("1,000 CAD"), Some("CAD".to_string()));
+        assert_eq!(currency("1,000"), None);
+    }
+
+    #[test]
+    fn test_parse_money_rejects_non_amounts() {
+        assert_eq!(parse_money(""), None);
+        assert_eq!(parse_money("n/a"), None);
+        assert_eq!(parse_money("(450"), None);
+        assert_eq!(parse_money("12%"), None);
+        assert_eq!(parse_money("$5 EUR"), None);
+        assert_eq!(parse_money("increased to $5 million"), None);
+    }
+}
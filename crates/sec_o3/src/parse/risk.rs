@@ -0,0 +1,199 @@
+//! Risk factor extraction from Item 1A.
+//!
+//! Item 1A of a 10-K lists each risk factor under its own heading, usually
+//! a bold sentence without a closing period or a bulleted line, followed
+//! by explanatory paragraphs. This module splits that text into one entry
+//! per risk factor.
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Characters used as bullets in plain-text renderings of filings.
+const BULLETS: &[char] = &['•', '●', '▪', '■', '◦', '·', '*', '-', '–'];
+
+/// Maximum length of a paragraph treated as a heading.
+const MAX_HEADING_LEN: usize = 400;
+
+static ITEM_HEADING: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^item\s*1a\b").unwrap());
+
+/// Split the text of Item 1A into individual risk factors.
+///
+/// A new factor starts at a bulleted paragraph or at a standalone heading
+/// (a short paragraph without closing punctuation). Headings with no text
+/// of their own that are immediately followed by another heading, such as
+/// "Risks Related to Our Business", are treated as category titles and
+/// dropped, as is any introduction before the first factor.
+///
+/// Each factor is returned as its heading followed by its paragraphs,
+/// separated by blank lines. If no structure is detected, the whole
+/// trimmed text is returned as a single item.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::risk::extract_risk_factors;
+///
+/// let text = "Our supply chain is concentrated in Asia\n\n\
+///             Disruptions could delay shipments.\n\n\
+///             We depend on key personnel\n\n\
+///             Losing executives could harm operations.";
+///
+/// let factors = extract_risk_factors(text);
+/// assert_eq!(factors.len(), 2);
+/// assert!(factors[1].starts_with("We depend on key personnel"));
+/// ```
+pub fn extract_risk_factors(item_1a_text: &str) -> Vec<String> {
+    let text = item_1a_text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let paragraphs = split_paragraphs(text);
+    let mut factors: Vec<Vec<String>> = Vec::new();
+    let mut current: Option<Vec<String>> = None;
+
+    for (i, paragraph) in paragraphs.iter().enumerate() {
+        if i == 0 && ITEM_HEADING.is_match(paragraph) {
+            continue;
+        }
+
+        let bulleted = paragraph.starts_with(BULLETS);
+        if bulleted || is_heading(paragraph) {
+            let next_is_heading = paragraphs
+                .get(i + 1)
+                .is_some_and(|next| next.starts_with(BULLETS) || is_heading(next));
+            if !bulleted && next_is_heading {
+                // Category title with no text of its own
+                continue;
+            }
+
+            factors.extend(current.take());
+            let heading = paragraph.trim_start_matches(BULLETS).trim_start().to_string();
+            current = Some(vec![heading]);
+        } else if let Some(factor) = current.as_mut() {
+            factor.push(paragraph.clone());
+        }
+    }
+    factors.extend(current);
+
+    if factors.is_empty() {
+        return vec![text.to_string()];
+    }
+
+    factors.into_iter().map(|factor| factor.join("\n\n")).collect()
+}
+
+/// Split text into paragraphs, joining hard-wrapped lines.
+///
+/// Paragraphs are separated by blank lines. Text without blank lines is
+/// treated as one paragraph per line.
+fn split_paragraphs(text: &str) -> Vec<String> {
+    let has_blank_lines = text.lines().any(|line| line.trim().is_empty());
+
+    if !has_blank_lines {
+        return text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+    paragraphs
+}
+
+/// Whether a paragraph looks like a standalone heading.
+fn is_heading(paragraph: &str) -> bool {
+    let paragraph = paragraph.trim();
+    paragraph.chars().count() <= MAX_HEADING_LEN
+        && paragraph.chars().next().is_some_and(char::is_alphanumeric)
+        && !paragraph.ends_with(['.', '!', '?', ':', ';', ','])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEM_1A: &str = "\
+Item 1A. Risk Factors
+
+You should carefully consider the risks described below. Any of them could
+materially affect our business.
+
+Risks Related to Our Business
+
+Our operations depend on a limited number of suppliers
+
+We source key components from a small group of manufacturers. Any disruption
+could delay product shipments.
+
+Supply constraints may also increase our costs.
+
+We face intense competition in all of our markets
+
+Competitors may introduce products at lower prices.
+
+Risks Related to Regulation
+
+Changes in tax law could adversely affect our results
+
+New legislation could increase our effective tax rate.";
+
+    #[test]
+    fn test_extract_three_factors() {
+        let factors = extract_risk_factors(ITEM_1A);
+
+        assert_eq!(factors.len(), 3);
+        assert_eq!(
+            factors[0],
+            "Our operations depend on a limited number of suppliers\n\n\
+             We source key components from a small group of manufacturers. Any disruption \
+             could delay product shipments.\n\n\
+             Supply constraints may also increase our costs."
+        );
+        assert!(factors[1].starts_with("We face intense competition in all of our markets\n\n"));
+        assert!(factors[2].ends_with("New legislation could increase our effective tax rate."));
+    }
+
+    #[test]
+    fn test_extract_bulleted_factors() {
+        let text = "Summary of risk factors:\n\
+                    • We may not be able to maintain profitability.\n\
+                    • Our stock price may be volatile.\n\
+                    • We rely on third-party cloud providers.";
+
+        let factors = extract_risk_factors(text);
+        assert_eq!(
+            factors,
+            vec![
+                "We may not be able to maintain profitability.",
+                "Our stock price may be volatile.",
+                "We rely on third-party cloud providers.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unstructured_text_returned_whole() {
+        let text = "  There are many risks. We describe them elsewhere.  ";
+        assert_eq!(
+            extract_risk_factors(text),
+            vec!["There are many risks. We describe them elsewhere."]
+        );
+        assert!(extract_risk_factors("   ").is_empty());
+    }
+}
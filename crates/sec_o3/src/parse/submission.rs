@@ -0,0 +1,412 @@
+//! Full submission text files.
+//!
+//! EDGAR's `<accession>.txt` holds the SEC header followed by every document
+//! in the filing, each wrapped in `<DOCUMENT>` tags with a short header:
+//!
+//! ```text
+//! <DOCUMENT>
+//! <TYPE>10-K
+//! <SEQUENCE>1
+//! <FILENAME>aapl-20230930.htm
+//! <DESCRIPTION>10-K
+//! <TEXT>
+//! ...
+//! </TEXT>
+//! </DOCUMENT>
+//! ```
+//!
+//! Binary documents (images, PDFs, ZIP archives) are embedded as uuencoded
+//! or base64 text; [`SubmissionDoc::decoded_bytes`] recovers their bytes.
+use base64::Engine;
+
+use crate::utils::filing_id;
+
+const DOCUMENT_START: &str = "<DOCUMENT>";
+const DOCUMENT_END: &str = "</DOCUMENT>";
+const TEXT_START: &str = "<TEXT>";
+const TEXT_END: &str = "</TEXT>";
+
+/// Document types of binary documents.
+const BINARY_TYPES: &[&str] = &["GRAPHIC", "ZIP", "PDF", "EXCEL"];
+
+/// File extensions of binary documents.
+const BINARY_EXTENSIONS: &[&str] = &["jpg", "jpeg", "gif", "png", "pdf", "zip", "xls", "xlsx"];
+
+/// Metadata of one document in a full submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocManifestEntry {
+    /// Document type (e.g., "10-K", "EX-21.1", "GRAPHIC")
+    pub doc_type: String,
+    /// Position of the document in the filing, starting at 1
+    pub sequence: Option<u32>,
+    /// File name within the filing directory
+    pub filename: Option<String>,
+    /// Filer-provided description
+    pub description: Option<String>,
+    /// Length in bytes of the document body between `<TEXT>` tags
+    pub byte_len: usize,
+}
+
+/// One document of a full submission: its metadata and body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionDoc<'a> {
+    /// Document type, sequence, file name, and description
+    pub entry: DocManifestEntry,
+    /// Document body between the `<TEXT>` tags
+    pub text: &'a str,
+}
+
+impl SubmissionDoc<'_> {
+    /// Canonical key of the document: `{cik10}-{accession_no_dashes}-{sequence}`.
+    ///
+    /// CIK and accession are normalized as in [`crate::utils::filing_id`].
+    /// EDGAR numbers every document; one without a `<SEQUENCE>` uses 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec_o3::parse::submission_documents;
+    ///
+    /// let text = "<DOCUMENT>\n<TYPE>EX-21.1\n<SEQUENCE>2\n<TEXT>\n</TEXT>\n</DOCUMENT>";
+    /// let document = &submission_documents(text)[0];
+    /// assert_eq!(
+    ///     document.document_id("320193", "0000320193-23-000106"),
+    ///     "0000320193-000032019323000106-2"
+    /// );
+    /// ```
+    pub fn document_id(&self, cik: &str, accession: &str) -> String {
+        format!("{}-{}", filing_id(cik, accession), self.entry.sequence.unwrap_or(0))
+    }
+
+    /// Bytes of a binary document, decoded from its text encoding.
+    ///
+    /// Uuencoded bodies (starting with a `begin <mode> <name>` line) are
+    /// recognized by shape alone. Base64 is only assumed when the document
+    /// is marked binary by its type (e.g., `GRAPHIC`) or file extension
+    /// (e.g., `.jpg`, `.pdf`) and every line is in the base64 alphabet.
+    /// Returns `None` for text documents and for bodies that fail to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec_o3::parse::submission_documents;
+    ///
+    /// let text = "<DOCUMENT>\n<TYPE>GRAPHIC\n<FILENAME>logo.gif\n<TEXT>\nR0lGODlh\n</TEXT>\n</DOCUMENT>";
+    /// let documents = submission_documents(text);
+    /// assert_eq!(documents[0].decoded_bytes().as_deref(), Some(&b"GIF89a"[..]));
+    /// ```
+    pub fn decoded_bytes(&self) -> Option<Vec<u8>> {
+        let body = self.text.trim();
+        if body.starts_with("begin ") {
+            return uudecode(body);
+        }
+        if !self.is_binary() || body.is_empty() {
+            return None;
+        }
+
+        let encoded: String = body.split_whitespace().collect();
+        let is_base64 = encoded
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'='));
+        if !is_base64 {
+            return None;
+        }
+        base64::engine::general_purpose::STANDARD.decode(encoded).ok()
+    }
+
+    /// Whether the type or file name marks the document as binary.
+    fn is_binary(&self) -> bool {
+        let extension = self
+            .entry
+            .filename
+            .as_deref()
+            .and_then(|name| name.rsplit_once('.'))
+            .map(|(_, extension)| extension.to_ascii_lowercase());
+
+        BINARY_TYPES.contains(&self.entry.doc_type.to_ascii_uppercase().as_str())
+            || extension.is_some_and(|extension| BINARY_EXTENSIONS.contains(&extension.as_str()))
+    }
+}
+
+/// Split a full submission into the contents of its `<DOCUMENT>` blocks.
+///
+/// Each slice starts after `<DOCUMENT>` and ends before `</DOCUMENT>`. An
+/// unterminated final document runs to the end of the text.
+pub fn split_documents(submission_text: &str) -> Vec<&str> {
+    let mut documents = Vec::new();
+    let mut rest = submission_text;
+
+    while let Some(start) = rest.find(DOCUMENT_START) {
+        rest = &rest[start + DOCUMENT_START.len()..];
+        match rest.find(DOCUMENT_END) {
+            Some(end) => {
+                documents.push(&rest[..end]);
+                rest = &rest[end + DOCUMENT_END.len()..];
+            }
+            None => {
+                documents.push(rest);
+                break;
+            }
+        }
+    }
+
+    documents
+}
+
+/// List every document contained in a full submission.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::submission_manifest;
+///
+/// let text = "<SEC-DOCUMENT>\n<DOCUMENT>\n<TYPE>EX-21.1\n<SEQUENCE>2\n<FILENAME>ex21.htm\n\
+///             <TEXT>\nSubsidiaries\n</TEXT>\n</DOCUMENT>\n</SEC-DOCUMENT>";
+///
+/// let manifest = submission_manifest(text);
+/// assert_eq!(manifest[0].doc_type, "EX-21.1");
+/// assert_eq!(manifest[0].filename.as_deref(), Some("ex21.htm"));
+/// ```
+pub fn submission_manifest(submission_text: &str) -> Vec<DocManifestEntry> {
+    split_documents(submission_text)
+        .into_iter()
+        .map(|document| parse_document(document).entry)
+        .collect()
+}
+
+/// Split a full submission into documents with their metadata and bodies.
+pub fn submission_documents(submission_text: &str) -> Vec<SubmissionDoc<'_>> {
+    split_documents(submission_text)
+        .into_iter()
+        .map(parse_document)
+        .collect()
+}
+
+fn parse_document(document: &str) -> SubmissionDoc<'_> {
+    let (header, body) = match document.find(TEXT_START) {
+        Some(start) => (&document[..start], &document[start + TEXT_START.len()..]),
+        None => (document, ""),
+    };
+    let body = body.find(TEXT_END).map_or(body, |end| &body[..end]);
+    let body = body
+        .strip_prefix("\r\n")
+        .or_else(|| body.strip_prefix('\n'))
+        .unwrap_or(body);
+
+    let mut entry = DocManifestEntry {
+        doc_type: String::new(),
+        sequence: None,
+        filename: None,
+        description: None,
+        byte_len: body.len(),
+    };
+
+    for line in header.lines() {
+        let Some((tag, value)) = line.trim().strip_prefix('<').and_then(|line| line.split_once('>')) else {
+            continue;
+        };
+        let value = value.trim();
+        match tag {
+            "TYPE" => entry.doc_type = value.to_string(),
+            "SEQUENCE" => entry.sequence = value.parse().ok(),
+            "FILENAME" => entry.filename = Some(value.to_string()),
+            "DESCRIPTION" => entry.description = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    SubmissionDoc { entry, text: body }
+}
+
+/// Decode a uuencoded body from its `begin` line through `end`.
+///
+/// Each line starts with a character giving its decoded length, followed by
+/// groups of four characters carrying six bits each (offset from space, with
+/// a backtick standing for zero). Trailing characters some encoders leave
+/// off a line decode as zero bits, but a line whose length claims more
+/// bytes than it carries is rejected.
+fn uudecode(body: &str) -> Option<Vec<u8>> {
+    let mut lines = body.lines().map(str::trim_end);
+    lines.next()?.strip_prefix("begin ")?;
+
+    let mut bytes = Vec::new();
+    for line in lines {
+        if line == "end" {
+            return Some(bytes);
+        }
+        let line = line.as_bytes();
+        let Some((&length, data)) = line.split_first() else {
+            continue;
+        };
+        if !(b' '..=b'`').contains(&length) || data.iter().any(|b| !(b' '..=b'`').contains(b)) {
+            return None;
+        }
+
+        let length = usize::from((length - b' ') & 0x3f);
+        let mut decoded = Vec::with_capacity(data.len() / 4 * 3 + 3);
+        for group in data.chunks(4) {
+            let mut sextets = [0u8; 4];
+            for (sextet, &b) in sextets.iter_mut().zip(group) {
+                *sextet = (b - b' ') & 0x3f;
+            }
+            decoded.push((sextets[0] << 2) | (sextets[1] >> 4));
+            decoded.push((sextets[1] << 4) | (sextets[2] >> 2));
+            decoded.push((sextets[2] << 6) | sextets[3]);
+        }
+        if length > decoded.len() {
+            return None;
+        }
+        decoded.truncate(length);
+        bytes.extend_from_slice(&decoded);
+    }
+
+    // No `end` line: the body was cut off
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBMISSION: &str = "<SEC-DOCUMENT>0000320193-23-000106.txt : 20231103
+<SEC-HEADER>0000320193-23-000106.hdr.sgml : 20231103
+ACCESSION NUMBER:\t\t0000320193-23-000106
+CONFORMED SUBMISSION TYPE:\t10-K
+PUBLIC DOCUMENT COUNT:\t\t3
+</SEC-HEADER>
+<DOCUMENT>
+<TYPE>10-K
+<SEQUENCE>1
+<FILENAME>aapl-20230930.htm
+<DESCRIPTION>10-K
+<TEXT>
+<html><body>FORM 10-K</body></html>
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-21.1
+<SEQUENCE>2
+<FILENAME>a10-kexhibit2119302023.htm
+<DESCRIPTION>EX-21.1
+<TEXT>
+Subsidiaries of Apple Inc.
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>GRAPHIC
+<SEQUENCE>3
+<FILENAME>aapl-20230930_g1.jpg
+<TEXT>
+begin 644 aapl-20230930_g1.jpg
+end
+</TEXT>
+</DOCUMENT>
+</SEC-DOCUMENT>
+";
+
+    #[test]
+    fn test_submission_manifest_lists_documents() {
+        let manifest = submission_manifest(SUBMISSION);
+
+        let types: Vec<&str> = manifest.iter().map(|e| e.doc_type.as_str()).collect();
+        assert_eq!(types, vec!["10-K", "EX-21.1", "GRAPHIC"]);
+
+        assert_eq!(
+            manifest[0],
+            DocManifestEntry {
+                doc_type: "10-K".to_string(),
+                sequence: Some(1),
+                filename: Some("aapl-20230930.htm".to_string()),
+                description: Some("10-K".to_string()),
+                byte_len: "<html><body>FORM 10-K</body></html>\n".len(),
+            }
+        );
+        assert_eq!(manifest[1].sequence, Some(2));
+        assert_eq!(manifest[2].filename.as_deref(), Some("aapl-20230930_g1.jpg"));
+        assert_eq!(manifest[2].description, None);
+    }
+
+    #[test]
+    fn test_document_id_is_normalized() {
+        let documents = submission_documents(SUBMISSION);
+
+        assert_eq!(
+            documents[1].document_id("CIK320193", "000032019323000106"),
+            "0000320193-000032019323000106-2"
+        );
+        assert_eq!(
+            documents[1].document_id("0000320193", "0000320193-23-000106"),
+            documents[1].document_id("320193", "000032019323000106")
+        );
+
+        let unnumbered = submission_documents("<DOCUMENT>\n<TYPE>EX-99\n<TEXT>\n</TEXT>\n</DOCUMENT>");
+        assert_eq!(
+            unnumbered[0].document_id("320193", "0000320193-23-000106"),
+            "0000320193-000032019323000106-0"
+        );
+    }
+
+    #[test]
+    fn test_decoded_bytes_of_binary_documents() {
+        let submission = format!(
+            "{}<DOCUMENT>
+<TYPE>GRAPHIC
+<SEQUENCE>4
+<FILENAME>chart.png
+<TEXT>
+iVBORw0KGgoAAAAN
+SUhEUg==
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>EX-99.1
+<SEQUENCE>5
+<FILENAME>ex991.txt
+<TEXT>
+QmFzZTY0
+</TEXT>
+</DOCUMENT>
+<DOCUMENT>
+<TYPE>GRAPHIC
+<SEQUENCE>6
+<FILENAME>cat.jpg
+<TEXT>
+begin 644 cat.jpg
+#0V%T
+`
+end
+</TEXT>
+</DOCUMENT>
+",
+            SUBMISSION
+        );
+        let documents = submission_documents(&submission);
+        let decoded: Vec<Option<Vec<u8>>> = documents.iter().map(SubmissionDoc::decoded_bytes).collect();
+
+        assert_eq!(documents.len(), 6);
+        assert_eq!(documents[3].entry.filename.as_deref(), Some("chart.png"));
+        assert_eq!(decoded[0], None);
+        assert_eq!(decoded[1], None);
+        // Empty uuencoded image in the captured submission
+        assert_eq!(decoded[2], Some(Vec::new()));
+        assert_eq!(decoded[3].as_deref(), Some(&b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"[..]));
+        // Base64-shaped text is left alone without a binary type or extension
+        assert_eq!(decoded[4], None);
+        assert_eq!(decoded[5].as_deref(), Some(&b"Cat"[..]));
+    }
+
+    #[test]
+    fn test_uudecode_rejects_truncated_or_invalid_body() {
+        assert_eq!(uudecode("begin 644 cat.jpg\n#0V%T\n"), None);
+        assert_eq!(uudecode("begin 644 cat.jpg\n#0v%t\nend"), None);
+        // Length byte claims six bytes, the line carries three
+        assert_eq!(uudecode("begin 644 cat.jpg\n&0V%T\n`\nend"), None);
+    }
+
+    #[test]
+    fn test_split_documents_unterminated() {
+        let documents = split_documents("header<DOCUMENT>\n<TYPE>8-K\n</DOCUMENT><DOCUMENT>\n<TYPE>EX-99.1");
+        assert_eq!(documents, vec!["\n<TYPE>8-K\n", "\n<TYPE>EX-99.1"]);
+        assert!(split_documents("no documents").is_empty());
+    }
+}
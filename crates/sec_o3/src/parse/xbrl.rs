@@ -0,0 +1,312 @@
+//! Streaming XBRL instance parsing.
+//!
+//! XBRL instance documents for large filers can exceed 100MB, so they are
+//! parsed with `quick-xml`'s pull reader instead of being loaded into a DOM.
+//! Facts are yielded one at a time and only the current fact is held in memory.
+use quick_xml::encoding::Decoder;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::io::BufRead;
+
+use super::resolve_xml_entity;
+use crate::{Error, Result};
+
+/// A single fact reported in an XBRL instance document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XbrlFact {
+    /// Qualified concept name (e.g., "us-gaap:Revenues")
+    pub name: String,
+    /// Identifier of the context the fact belongs to
+    pub context_ref: String,
+    /// Identifier of the unit (numeric facts only)
+    pub unit_ref: Option<String>,
+    /// Reported precision (e.g., "-6", "INF")
+    pub decimals: Option<String>,
+    /// Raw fact value as it appears in the document
+    pub value: String,
+}
+
+/// Iterator over the facts of an XBRL instance document.
+///
+/// Created by [`iter_facts`].
+pub struct FactIter<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+/// Iterate over the facts of an XBRL instance document with bounded memory.
+///
+/// Any element carrying a `contextRef` attribute is treated as a fact. Text
+/// nested inside a fact (e.g., in text blocks) is concatenated into its value.
+/// Parsing stops after the first XML error, which is yielded as an item.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::xbrl::iter_facts;
+///
+/// let xml = r#"<xbrli:xbrl>
+///   <us-gaap:Revenues contextRef="FY2023" unitRef="usd" decimals="-6">383285000000</us-gaap:Revenues>
+/// </xbrli:xbrl>"#;
+///
+/// let facts: Vec<_> = iter_facts(xml.as_bytes()).collect::<sec_o3::Result<_>>().unwrap();
+/// assert_eq!(facts[0].name, "us-gaap:Revenues");
+/// assert_eq!(facts[0].value, "383285000000");
+/// ```
+pub fn iter_facts<R: BufRead>(reader: R) -> FactIter<R> {
+    FactIter {
+        reader: Reader::from_reader(reader),
+        buf: Vec::new(),
+        done: false,
+    }
+}
+
+/// Fact attributes captured from a start tag, owned so the read buffer can be reused.
+struct FactHeader {
+    name: String,
+    context_ref: String,
+    unit_ref: Option<String>,
+    decimals: Option<String>,
+}
+
+impl FactHeader {
+    /// Build a header from a start tag, returning `None` for non-fact elements.
+    fn from_start(start: &BytesStart, decoder: Decoder) -> Result<Option<Self>> {
+        let mut context_ref = None;
+        let mut unit_ref = None;
+        let mut decimals = None;
+
+        for attr in start.attributes() {
+            let attr = attr.map_err(|e| Error::XmlError(e.to_string()))?;
+            let value = attr
+                .decode_and_unescape_value(decoder)
+                .map_err(|e| Error::XmlError(e.to_string()))?
+                .into_owned();
+
+            match attr.key.as_ref() {
+                b"contextRef" => context_ref = Some(value),
+                b"unitRef" => unit_ref = Some(value),
+                b"decimals" => decimals = Some(value),
+                _ => {}
+            }
+        }
+
+        Ok(context_ref.map(|context_ref| Self {
+            name: String::from_utf8_lossy(start.name().as_ref()).into_owned(),
+            context_ref,
+            unit_ref,
+            decimals,
+        }))
+    }
+
+    fn into_fact(self, value: String) -> XbrlFact {
+        XbrlFact {
+            name: self.name,
+            context_ref: self.context_ref,
+            unit_ref: self.unit_ref,
+            decimals: self.decimals,
+            value,
+        }
+    }
+}
+
+impl<R: BufRead> FactIter<R> {
+    /// Read the text content of the current fact up to its matching end tag.
+    fn read_value(&mut self) -> Result<String> {
+        let mut value = String::new();
+        let mut depth = 0usize;
+
+        loop {
+            self.buf.clear();
+            match self.reader.read_event_into(&mut self.buf) {
+                Ok(Event::Start(_)) => depth += 1,
+                Ok(Event::End(_)) if depth == 0 => return Ok(value.trim().to_string()),
+                Ok(Event::End(_)) => depth -= 1,
+                Ok(Event::Text(text)) => {
+                    value.push_str(&text.decode().map_err(|e| Error::XmlError(e.to_string()))?);
+                }
+                Ok(Event::CData(data)) => {
+                    value.push_str(&data.decode().map_err(|e| Error::XmlError(e.to_string()))?);
+                }
+                Ok(Event::GeneralRef(entity)) => value.push_str(&resolve_xml_entity(&entity)?),
+                Ok(Event::Eof) => return Err(Error::XmlError("Unexpected end of document inside fact".into())),
+                Ok(_) => {}
+                Err(e) => return Err(Error::XmlError(e.to_string())),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for FactIter<R> {
+    type Item = Result<XbrlFact>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            self.buf.clear();
+            let decoder = self.reader.decoder();
+            let result = match self.reader.read_event_into(&mut self.buf) {
+                Ok(Event::Start(start)) => match FactHeader::from_start(&start, decoder) {
+                    Ok(Some(header)) => Some(self.read_value().map(|value| header.into_fact(value))),
+                    Ok(None) => None,
+                    Err(e) => Some(Err(e)),
+                },
+                Ok(Event::Empty(start)) => FactHeader::from_start(&start, decoder)
+                    .map(|header| header.map(|h| h.into_fact(String::new())))
+                    .transpose(),
+                Ok(Event::Eof) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(_) => None,
+                Err(e) => Some(Err(Error::XmlError(e.to_string()))),
+            };
+
+            if let Some(item) = result {
+                if item.is_err() {
+                    self.done = true;
+                }
+                return Some(item);
+            }
+        }
+    }
+}
+
+/// Check that a document is a well-formed XBRL instance.
+///
+/// Streams the document once, verifying that every element is closed and
+/// that the root element is `xbrl` (in any namespace prefix). Catches
+/// truncated downloads and HTML error pages without building a DOM.
+///
+/// # Errors
+///
+/// Returns `Error::XmlError` describing the first problem found.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::parse::xbrl::check_well_formed;
+///
+/// assert!(check_well_formed("<xbrli:xbrl><dei:DocumentType contextRef=\"c\">10-K</dei:DocumentType></xbrli:xbrl>").is_ok());
+/// assert!(check_well_formed("<xbrli:xbrl><dei:DocumentType contextRef=\"c\">10-K").is_err());
+/// ```
+pub fn check_well_formed(xml: &str) -> Result<()> {
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0usize;
+    let mut seen_root = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => {
+                if depth == 0 {
+                    check_root(&start, seen_root)?;
+                    seen_root = true;
+                }
+                depth += 1;
+            }
+            Ok(Event::Empty(start)) if depth == 0 => {
+                check_root(&start, seen_root)?;
+                seen_root = true;
+            }
+            Ok(Event::End(_)) => depth -= 1,
+            Ok(Event::Eof) if depth > 0 => {
+                return Err(Error::XmlError(format!(
+                    "Truncated XBRL instance: {} unclosed element(s)",
+                    depth
+                )))
+            }
+            Ok(Event::Eof) if !seen_root => return Err(Error::XmlError("Empty XBRL instance".to_string())),
+            Ok(Event::Eof) => return Ok(()),
+            Ok(_) => {}
+            Err(e) => return Err(Error::XmlError(e.to_string())),
+        }
+    }
+}
+
+/// Ensure a top-level element is the only root and is named `xbrl`.
+fn check_root(start: &BytesStart, seen_root: bool) -> Result<()> {
+    if seen_root {
+        return Err(Error::XmlError("Multiple root elements".to_string()));
+    }
+
+    let name = start.local_name();
+    if name.as_ref() != b"xbrl" {
+        return Err(Error::XmlError(format!(
+            "Unexpected root element: {}",
+            String::from_utf8_lossy(name.as_ref())
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<xbrli:xbrl xmlns:xbrli="http://www.xbrl.org/2003/instance" xmlns:us-gaap="http://fasb.org/us-gaap/2023">
+  <xbrli:context id="FY2023">
+    <xbrli:entity><xbrli:identifier scheme="http://www.sec.gov/CIK">0000320193</xbrli:identifier></xbrli:entity>
+    <xbrli:period><xbrli:startDate>2022-09-25</xbrli:startDate><xbrli:endDate>2023-09-30</xbrli:endDate></xbrli:period>
+  </xbrli:context>
+  <xbrli:unit id="usd"><xbrli:measure>iso4217:USD</xbrli:measure></xbrli:unit>
+  <dei:DocumentType contextRef="FY2023">10-K</dei:DocumentType>
+  <us-gaap:Revenues contextRef="FY2023" unitRef="usd" decimals="-6">383285000000</us-gaap:Revenues>
+  <us-gaap:NetIncomeLoss contextRef="FY2023" unitRef="usd" decimals="-6">96995000000</us-gaap:NetIncomeLoss>
+  <us-gaap:GoodwillImpairmentLoss contextRef="FY2023" unitRef="usd" xsi:nil="true"/>
+  <us-gaap:SegmentReportingDisclosureTextBlock contextRef="FY2023">Segments &amp; <b>regions</b></us-gaap:SegmentReportingDisclosureTextBlock>
+</xbrli:xbrl>"#;
+
+    #[test]
+    fn test_iter_facts_yields_all_facts() {
+        let facts: Vec<XbrlFact> = iter_facts(INSTANCE.as_bytes())
+            .collect::<Result<_>>()
+            .expect("instance should parse");
+
+        let names: Vec<&str> = facts.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "dei:DocumentType",
+                "us-gaap:Revenues",
+                "us-gaap:NetIncomeLoss",
+                "us-gaap:GoodwillImpairmentLoss",
+                "us-gaap:SegmentReportingDisclosureTextBlock",
+            ]
+        );
+
+        assert_eq!(facts[1].context_ref, "FY2023");
+        assert_eq!(facts[1].unit_ref.as_deref(), Some("usd"));
+        assert_eq!(facts[1].decimals.as_deref(), Some("-6"));
+        assert_eq!(facts[1].value, "383285000000");
+        assert_eq!(facts[0].unit_ref, None);
+        assert_eq!(facts[3].value, "");
+        assert_eq!(facts[4].value, "Segments & regions");
+    }
+
+    #[test]
+    fn test_iter_facts_truncated_document() {
+        let truncated = &INSTANCE[..INSTANCE.find("96995").unwrap()];
+        let results: Vec<Result<XbrlFact>> = iter_facts(truncated.as_bytes()).collect();
+
+        assert!(results.last().unwrap().is_err());
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+    }
+
+    #[test]
+    fn test_check_well_formed() {
+        assert!(check_well_formed(INSTANCE).is_ok());
+
+        let truncated = &INSTANCE[..INSTANCE.len() / 2];
+        assert!(check_well_formed(truncated).is_err());
+
+        assert!(check_well_formed("").is_err());
+        assert!(check_well_formed("<html><body>Access Denied</body></html>").is_err());
+        assert!(check_well_formed("<xbrli:xbrl></xbrli:xbrl><xbrli:xbrl/>").is_err());
+    }
+}
@@ -0,0 +1,29 @@
+//! Fixtures shared by unit tests across modules.
+use wiremock::MockServer;
+
+use crate::filings::Filing;
+use crate::utils::str_to_utc_datetime;
+use crate::Client;
+
+/// Apple's fiscal 2023 10-K.
+pub(crate) fn sample_filing() -> Filing {
+    Filing {
+        cik: "320193".to_string(),
+        accession_number: "0000320193-23-000106".to_string(),
+        form_type: "10-K".to_string(),
+        acceptance_date: str_to_utc_datetime("2023-11-03T18:01:14.000Z").unwrap(),
+        primary_document: "aapl-20230930.htm".to_string(),
+        is_xbrl: true,
+        size: None,
+        report_date: None,
+    }
+}
+
+/// Client pointed at a mock server.
+pub(crate) fn mock_client(server: &MockServer) -> Client {
+    Client::builder()
+        .user_agent("TestApp test@example.com")
+        .base_url(server.uri())
+        .build()
+        .unwrap()
+}
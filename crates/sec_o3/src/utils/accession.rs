@@ -0,0 +1,130 @@
+//! SEC accession numbers.
+//!
+//! An accession number identifies a single submission. EDGAR writes it
+//! dashed (`0000320193-23-000106`: filer ID, year, sequence) in index
+//! files and full-text names, and undashed (`000032019323000106`) in
+//! archive directory paths. [`AccessionNumber`] accepts either form.
+use std::fmt;
+use std::str::FromStr;
+
+use super::normalize_cik;
+use crate::{Error, Result};
+
+/// Number of digits in an accession number, without dashes.
+const ACCESSION_DIGITS: usize = 18;
+
+/// A validated SEC accession number.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::AccessionNumber;
+///
+/// let dashed: AccessionNumber = "0000320193-23-000106".parse().unwrap();
+/// let undashed: AccessionNumber = "000032019323000106".parse().unwrap();
+///
+/// assert_eq!(dashed, undashed);
+/// assert_eq!(dashed.dashed(), "0000320193-23-000106");
+/// assert_eq!(dashed.undashed(), "000032019323000106");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccessionNumber {
+    digits: String,
+}
+
+impl AccessionNumber {
+    /// Parse an accession number in dashed or undashed form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Custom` unless the input is 18 digits, optionally
+    /// dashed as `NNNNNNNNNN-NN-NNNNNN`.
+    pub fn parse(accession: &str) -> Result<Self> {
+        let accession = accession.trim();
+        let bytes = accession.as_bytes();
+
+        let digits = match bytes.len() {
+            ACCESSION_DIGITS => accession.to_string(),
+            20 if bytes[10] == b'-' && bytes[13] == b'-' => accession.replace('-', ""),
+            _ => String::new(),
+        };
+
+        if digits.len() != ACCESSION_DIGITS || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::Custom(format!("Invalid accession number: {}", accession)));
+        }
+
+        Ok(Self { digits })
+    }
+
+    /// Dashed form used in index and full-text file names (e.g., "0000320193-23-000106").
+    pub fn dashed(&self) -> String {
+        format!("{}-{}-{}", &self.digits[..10], &self.digits[10..12], &self.digits[12..])
+    }
+
+    /// Undashed form used in archive directory paths (e.g., "000032019323000106").
+    pub fn undashed(&self) -> &str {
+        &self.digits
+    }
+}
+
+impl FromStr for AccessionNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for AccessionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.dashed())
+    }
+}
+
+/// Canonical key of a filing: `{cik10}-{accession_no_dashes}`.
+///
+/// The CIK is zero-padded to 10 digits and the accession number undashed,
+/// so the same filing always gets the same key. Malformed input is kept
+/// as-is (trimmed, dashes removed) rather than rejected.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::filing_id;
+///
+/// assert_eq!(filing_id("320193", "0000320193-23-000106"), "0000320193-000032019323000106");
+/// assert_eq!(filing_id("CIK0000320193", "000032019323000106"), "0000320193-000032019323000106");
+/// ```
+pub fn filing_id(cik: &str, accession: &str) -> String {
+    let cik = normalize_cik(cik).unwrap_or_else(|_| cik.trim().to_string());
+    let accession = AccessionNumber::parse(accession)
+        .map(|acc| acc.undashed().to_string())
+        .unwrap_or_else(|_| accession.trim().replace('-', ""));
+
+    format!("{}-{}", cik, accession)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accession_both_forms_equal() {
+        let dashed = AccessionNumber::parse("0000320193-23-000106").unwrap();
+        let undashed = AccessionNumber::parse(" 000032019323000106 ").unwrap();
+
+        assert_eq!(dashed, undashed);
+        assert_eq!(undashed.dashed(), "0000320193-23-000106");
+        assert_eq!(dashed.undashed(), "000032019323000106");
+        assert_eq!(undashed.to_string(), "0000320193-23-000106");
+    }
+
+    #[test]
+    fn test_accession_rejects_malformed() {
+        assert!(AccessionNumber::parse("").is_err());
+        assert!(AccessionNumber::parse("0000320193-23-00010").is_err());
+        assert!(AccessionNumber::parse("000032019-323-000106").is_err());
+        assert!(AccessionNumber::parse("0000320193-2X-000106").is_err());
+        assert!(AccessionNumber::parse("0000320193230001067").is_err());
+    }
+}
@@ -13,7 +13,8 @@ use ahash::RandomState;
 use futures::StreamExt;
 use moka::future::Cache;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
 use std::time::Duration;
 
 use crate::{Client, Error, Result};
@@ -69,13 +70,16 @@ pub async fn ticker_to_cik(ticker: &str) -> Result<String> {
 
 /// Look up multiple companies by ticker symbol (case-insensitive).
 ///
-/// Returns Vector of (ticker, CIK) tuples for successfully found tickers.
-/// Silently skips tickers that aren't found.
+/// Returns Vector of (ticker, CIK) tuples for successfully found tickers,
+/// in input order. Silently skips tickers that aren't found.
+///
+/// Cached tickers are resolved directly; the SEC ticker file is downloaded
+/// at most once for all remaining tickers, and their CIKs are cached.
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use sec_o3::cik::batch_ticker_lookup;
+/// use sec_o3::utils::cik::batch_ticker_lookup;
 ///
 /// #[tokio::main]
 /// async fn main() -> sec_o3::Result<()> {
@@ -88,15 +92,38 @@ pub async fn ticker_to_cik(ticker: &str) -> Result<String> {
 /// }
 /// ```
 pub async fn batch_ticker_lookup(tickers: &[&str]) -> Result<Vec<(String, String)>> {
-    let mut results = Vec::with_capacity(tickers.len());
+    batch_ticker_lookup_with(tickers, fetch_ticker_data).await
+}
+
+/// Batch lookup using `fetch` to load the ticker map on a cache miss.
+async fn batch_ticker_lookup_with<F, Fut>(tickers: &[&str], fetch: F) -> Result<Vec<(String, String)>>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<HashMap<String, TickerEntry>>>,
+{
+    let mut resolved: Vec<(String, Option<String>)> = Vec::with_capacity(tickers.len());
 
     for ticker in tickers {
-        if let Ok(cik) = ticker_to_cik(ticker).await {
-            results.push((ticker.to_uppercase(), cik));
+        let ticker_upper = ticker.to_uppercase();
+        let cik = CACHE.get(&ticker_upper).await;
+        resolved.push((ticker_upper, cik));
+    }
+
+    if resolved.iter().any(|(_, cik)| cik.is_none()) {
+        let data = fetch().await?;
+
+        for (ticker, cik) in resolved.iter_mut().filter(|(_, cik)| cik.is_none()) {
+            if let Some(entry) = data.get(ticker) {
+                CACHE.insert(ticker.clone(), entry.cik.clone()).await;
+                *cik = Some(entry.cik.clone());
+            }
         }
     }
 
-    Ok(results)
+    Ok(resolved
+        .into_iter()
+        .filter_map(|(ticker, cik)| cik.map(|cik| (ticker, cik)))
+        .collect())
 }
 
 /// Look up all tickers in parallel and populate the cache.
@@ -139,6 +166,9 @@ async fn fetch_cik_by_ticker(ticker: &str) -> Result<String> {
         .ok_or_else(|| Error::NotFound(format!("Ticker not found: {}", ticker)))
 }
 
+/// SEC ticker file, one `ticker\tcik` pair per line.
+const TICKER_URL: &str = "https://www.sec.gov/include/ticker.txt"; // TODO: CHANGE THIS TO exchange.json
+
 /// Fetch and parse the complete ticker-to-CIK mapping from SEC.
 ///
 /// The SEC provides this as a tab-delimited text file with format:
@@ -146,47 +176,114 @@ async fn fetch_cik_by_ticker(ticker: &str) -> Result<String> {
 async fn fetch_ticker_data() -> Result<HashMap<String, TickerEntry>> {
     let client = Client::from_env().unwrap_or_else(|_| Client::new("sec_o3", "default@example.com"));
 
-    let url = "https://www.sec.gov/include/ticker.txt"; // TODO: CHANGE THIS TO exchange.json
+    let text = fetch_ticker_text(&client).await?;
 
-    let text = client
-        .get_text(url)
-        .await
-        .map_err(|e| Error::Custom(format!("Failed to fetch ticker data: {}", e)))?;
+    let data: HashMap<String, TickerEntry> = parse_ticker_lines(&text)
+        .map(|(ticker, cik)| (ticker.clone(), TickerEntry { ticker, cik }))
+        .collect();
 
-    let mut data = HashMap::new();
+    if data.is_empty() {
+        return Err(Error::Custom("Empty ticker data received".to_string()));
+    }
 
-    for line in text.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+    Ok(data)
+}
 
+/// Map each CIK in the SEC ticker file to its ticker.
+///
+/// Companies with several share classes are listed once per ticker; the
+/// first one in the file is kept.
+pub(crate) async fn fetch_tickers_by_cik(client: &Client) -> Result<HashMap<String, String>> {
+    let text = fetch_ticker_text(client).await?;
+
+    let mut tickers = HashMap::new();
+    for (ticker, cik) in parse_ticker_lines(&text) {
+        tickers.entry(cik).or_insert(ticker);
+    }
+
+    Ok(tickers)
+}
+
+async fn fetch_ticker_text(client: &Client) -> Result<String> {
+    client
+        .get_text(TICKER_URL)
+        .await
+        .map_err(|e| Error::Custom(format!("Failed to fetch ticker data: {}", e)))
+}
+
+/// Parse ticker file lines into uppercase tickers and 10-digit CIKs, in
+/// file order. Malformed lines are skipped.
+fn parse_ticker_lines(text: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    text.lines().filter_map(|line| {
         // Parse tab-delimited: ticker\tcik
-        let parts: Vec<&str> = line.split('\t').collect();
+        let parts: Vec<&str> = line.trim().split('\t').collect();
         if parts.len() != 2 {
-            continue; // Skip malformed lines
+            return None;
         }
 
-        let ticker = parts[0].trim().to_uppercase();
-        let cik = parts[1].trim();
-
         // Parse CIK as number to validate, then format with leading zeros
-        if let Ok(cik_num) = cik.parse::<u64>() {
-            data.insert(
-                ticker.clone(),
-                TickerEntry {
-                    ticker: ticker.clone(),
-                    cik: format!("{:010}", cik_num),
-                },
-            );
-        }
+        let cik_num = parts[1].trim().parse::<u64>().ok()?;
+        Some((parts[0].trim().to_uppercase(), format!("{:010}", cik_num)))
+    })
+}
+
+/// Normalize a CIK to the 10-digit zero-padded form.
+///
+/// Accepts surrounding whitespace, an optional `CIK` prefix, and any
+/// amount of leading zeros.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidCik` if the CIK is empty, non-numeric, or longer
+/// than 10 digits.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::cik::normalize_cik;
+///
+/// assert_eq!(normalize_cik("320193").unwrap(), "0000320193");
+/// assert_eq!(normalize_cik("CIK0000320193").unwrap(), "0000320193");
+/// assert!(normalize_cik("AAPL").is_err());
+/// ```
+pub fn normalize_cik(cik: &str) -> Result<String> {
+    let trimmed = cik.trim();
+    let digits = trimmed
+        .strip_prefix("CIK")
+        .or_else(|| trimmed.strip_prefix("cik"))
+        .unwrap_or(trimmed);
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidCik(cik.to_string()));
     }
 
-    if data.is_empty() {
-        return Err(Error::Custom("Empty ticker data received".to_string()));
+    let significant = digits.trim_start_matches('0');
+    if significant.len() > 10 {
+        return Err(Error::InvalidCik(cik.to_string()));
     }
 
-    Ok(data)
+    Ok(format!("{:0>10}", significant))
+}
+
+/// Normalize many CIKs at once, skipping any that are invalid.
+///
+/// Valid CIKs are returned in input order, duplicates included; see
+/// [`normalize_cik`] for the accepted formats.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::cik::normalize_ciks;
+///
+/// assert_eq!(normalize_ciks(&["320193", "AAPL", "CIK789019"]), ["0000320193", "0000789019"]);
+/// ```
+pub fn normalize_ciks(ciks: &[impl AsRef<str>]) -> Vec<String> {
+    ciks.iter().filter_map(|cik| normalize_cik(cik.as_ref()).ok()).collect()
+}
+
+/// Normalize many CIKs, skipping invalid ones, into a sorted set without duplicates.
+pub fn normalize_ciks_dedup(ciks: &[impl AsRef<str>]) -> BTreeSet<String> {
+    ciks.iter().filter_map(|cik| normalize_cik(cik.as_ref()).ok()).collect()
 }
 
 /// Get the current cache size (for debugging/monitoring).
@@ -202,6 +299,44 @@ pub async fn clear_cache() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_normalize_cik() {
+        assert_eq!(normalize_cik("320193").unwrap(), "0000320193");
+        assert_eq!(normalize_cik(" 0000320193 ").unwrap(), "0000320193");
+        assert_eq!(normalize_cik("cik320193").unwrap(), "0000320193");
+        assert_eq!(normalize_cik("0").unwrap(), "0000000000");
+
+        assert!(matches!(normalize_cik(""), Err(Error::InvalidCik(_))));
+        assert!(normalize_cik("32O193").is_err());
+        assert!(normalize_cik("12345678901").is_err());
+    }
+
+    #[test]
+    fn test_normalize_ciks_mixed_formats() {
+        let ciks = [
+            "320193",
+            " CIK0000320193 ",
+            "cik789019",
+            "not a cik",
+            "0000789019",
+            "1318605",
+            "",
+        ];
+
+        assert_eq!(
+            normalize_ciks(&ciks),
+            ["0000320193", "0000320193", "0000789019", "0000789019", "0001318605"]
+        );
+        assert_eq!(
+            normalize_ciks_dedup(&ciks).into_iter().collect::<Vec<_>>(),
+            ["0000320193", "0000789019", "0001318605"]
+        );
+
+        let owned: Vec<String> = vec!["42".to_string()];
+        assert_eq!(normalize_ciks(&owned), ["0000000042"]);
+    }
 
     #[tokio::test]
     async fn test_ticker_to_cik() {
@@ -228,6 +363,47 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_batch_lookup_fetches_once() {
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            let data = [("ZZBA", "0000000001"), ("ZZBB", "0000000002"), ("ZZBC", "0000000003")]
+                .into_iter()
+                .map(|(ticker, cik)| {
+                    let entry = TickerEntry {
+                        ticker: ticker.to_string(),
+                        cik: cik.to_string(),
+                    };
+                    (ticker.to_string(), entry)
+                })
+                .collect();
+            Ok(data)
+        };
+
+        let results = batch_ticker_lookup_with(&["zzba", "ZZBB", "ZZBC", "ZZBMISSING"], fetch)
+            .await
+            .unwrap();
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            results,
+            vec![
+                ("ZZBA".to_string(), "0000000001".to_string()),
+                ("ZZBB".to_string(), "0000000002".to_string()),
+                ("ZZBC".to_string(), "0000000003".to_string()),
+            ]
+        );
+
+        // Fully cached batches don't fetch at all
+        let cached = batch_ticker_lookup_with(&["ZZBA", "ZZBC"], || async {
+            Err(Error::Custom("should not fetch".to_string()))
+        })
+        .await
+        .unwrap();
+        assert_eq!(cached.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_populate_cache() {
         let result = populate_cache().await;
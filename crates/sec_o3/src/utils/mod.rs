@@ -8,8 +8,17 @@
 /// The `utils` module centralizes small but frequently used helper functions that
 /// support consistent string formatting and data access patterns.
 ///
+pub mod accession;
 pub mod cik;
-pub use cik::{batch_ticker_lookup, ticker_to_cik};
+pub mod urls;
+pub use accession::{filing_id, AccessionNumber};
+pub use cik::{batch_ticker_lookup, normalize_cik, normalize_ciks, normalize_ciks_dedup, ticker_to_cik};
+pub use urls::{
+    build_browse_edgar_url, build_company_search_url, build_current_events_page_url, build_current_events_url,
+    build_filing_dir_url, build_filing_url, build_full_text_url, build_index_json_url, build_insider_dataset_url,
+    build_public_filing_url, try_build_filing_dir_url, try_build_filing_url, try_build_full_text_url, url_kind,
+    UrlKind,
+};
 
 use crate::{Error, Result};
 use chrono::{DateTime, Utc};
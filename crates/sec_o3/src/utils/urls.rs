@@ -0,0 +1,508 @@
+//! URL builders for EDGAR archive paths.
+//!
+//! Archive paths mix the undashed accession number (directory) with the
+//! dashed one (file names). These builders accept accession numbers in
+//! either form and normalize them through [`AccessionNumber`]. CIKs are
+//! written without the `CIK` prefix and leading zeros, as in EDGAR's own
+//! archive links.
+//!
+//! The `build_*` archive builders pass malformed accession numbers through
+//! unchanged; the `try_build_*` variants reject them instead.
+use super::accession::AccessionNumber;
+use super::cik::normalize_cik;
+use crate::Result;
+
+/// Root of the EDGAR filing archives.
+const ARCHIVES_BASE: &str = "https://www.sec.gov/Archives/edgar/data";
+
+/// Root of the insider transactions (Forms 3, 4, and 5) structured datasets.
+const INSIDER_DATASETS_BASE: &str = "https://www.sec.gov/files/structureddata/data/insider-transactions-data-sets";
+
+/// Classic EDGAR company search endpoint.
+const BROWSE_EDGAR_BASE: &str = "https://www.sec.gov/cgi-bin/browse-edgar";
+
+/// Dashed and undashed forms of an accession number.
+///
+/// Inputs that are not valid accession numbers are passed through with
+/// dashes removed for the directory form, as before normalization existed.
+fn accession_forms(accession: &str) -> (String, String) {
+    match AccessionNumber::parse(accession) {
+        Ok(acc) => (acc.dashed(), acc.undashed().to_string()),
+        Err(_) => (accession.to_string(), accession.replace('-', "")),
+    }
+}
+
+/// CIK as it appears in archive paths (e.g., "0000320193" -> "320193").
+///
+/// Inputs that are not valid CIKs are passed through trimmed.
+fn archive_cik(cik: &str) -> String {
+    match normalize_cik(cik) {
+        Ok(padded) => match padded.trim_start_matches('0') {
+            "" => "0".to_string(),
+            cik => cik.to_string(),
+        },
+        Err(_) => cik.trim().to_string(),
+    }
+}
+
+/// Build the URL of a filing's archive directory (with trailing slash).
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_filing_dir_url;
+///
+/// assert_eq!(
+///     build_filing_dir_url("320193", "0000320193-23-000106"),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/"
+/// );
+/// ```
+pub fn build_filing_dir_url(cik: &str, accession: &str) -> String {
+    let (_, undashed) = accession_forms(accession);
+    format!("{}/{}/{}/", ARCHIVES_BASE, archive_cik(cik), undashed)
+}
+
+/// Build the URL of a filing's human-readable `-index.html` page.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_filing_url;
+///
+/// assert_eq!(
+///     build_filing_url("320193", "000032019323000106"),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106-index.html"
+/// );
+/// ```
+pub fn build_filing_url(cik: &str, accession: &str) -> String {
+    let (dashed, undashed) = accession_forms(accession);
+    format!(
+        "{}/{}/{}/{}-index.html",
+        ARCHIVES_BASE,
+        archive_cik(cik),
+        undashed,
+        dashed
+    )
+}
+
+/// Build the public `-index.htm` URL of a filing, as linked from EDGAR search.
+///
+/// EDGAR serves the same page at both `-index.htm` and
+/// [`-index.html`](build_filing_url); this is the form EDGAR itself links
+/// to, so it is the one to cite.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_public_filing_url;
+///
+/// assert_eq!(
+///     build_public_filing_url("0000320193", "000032019323000106"),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106-index.htm"
+/// );
+/// ```
+pub fn build_public_filing_url(cik: &str, accession: &str) -> String {
+    let (dashed, undashed) = accession_forms(accession);
+    format!(
+        "{}/{}/{}/{}-index.htm",
+        ARCHIVES_BASE,
+        archive_cik(cik),
+        undashed,
+        dashed
+    )
+}
+
+/// Build the URL of a filing directory's machine-readable `index.json` listing.
+///
+/// The JSON counterpart of [`build_filing_url`]'s human-readable page.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_index_json_url;
+///
+/// assert_eq!(
+///     build_index_json_url("0000320193", "0000320193-23-000106"),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/index.json"
+/// );
+/// ```
+pub fn build_index_json_url(cik: &str, accession: &str) -> String {
+    format!("{}index.json", build_filing_dir_url(cik, accession))
+}
+
+/// Build the URL of a filing's full submission text file (`<accession>.txt`).
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_full_text_url;
+///
+/// assert_eq!(
+///     build_full_text_url("320193", "000032019323000106"),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106.txt"
+/// );
+/// ```
+pub fn build_full_text_url(cik: &str, accession: &str) -> String {
+    let (dashed, undashed) = accession_forms(accession);
+    format!("{}/{}/{}/{}.txt", ARCHIVES_BASE, archive_cik(cik), undashed, dashed)
+}
+
+/// Build the URL of a filing's archive directory, rejecting malformed accession numbers.
+///
+/// # Errors
+///
+/// Returns `Error::Custom` unless the accession number is 18 digits,
+/// optionally dashed as `NNNNNNNNNN-NN-NNNNNN`.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::try_build_filing_dir_url;
+///
+/// assert_eq!(
+///     try_build_filing_dir_url("320193", "0000320193-23-000106").unwrap(),
+///     "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/"
+/// );
+/// assert!(try_build_filing_dir_url("320193", "0000320193-23").is_err());
+/// ```
+pub fn try_build_filing_dir_url(cik: &str, accession: &str) -> Result<String> {
+    let accession = AccessionNumber::parse(accession)?;
+    Ok(build_filing_dir_url(cik, accession.undashed()))
+}
+
+/// Build the URL of a filing's `-index.html` page, rejecting malformed accession numbers.
+///
+/// # Errors
+///
+/// Returns `Error::Custom` unless the accession number is 18 digits,
+/// optionally dashed as `NNNNNNNNNN-NN-NNNNNN`.
+pub fn try_build_filing_url(cik: &str, accession: &str) -> Result<String> {
+    let accession = AccessionNumber::parse(accession)?;
+    Ok(build_filing_url(cik, accession.undashed()))
+}
+
+/// Build the URL of a filing's full submission text file, rejecting malformed accession numbers.
+///
+/// # Errors
+///
+/// Returns `Error::Custom` unless the accession number is 18 digits,
+/// optionally dashed as `NNNNNNNNNN-NN-NNNNNN`.
+pub fn try_build_full_text_url(cik: &str, accession: &str) -> Result<String> {
+    let accession = AccessionNumber::parse(accession)?;
+    Ok(build_full_text_url(cik, accession.undashed()))
+}
+
+/// Build a browse-edgar company filing list URL (Atom output).
+///
+/// Numeric CIKs are zero-padded to 10 digits; anything else (e.g., a
+/// ticker, which browse-edgar also accepts) is passed through trimmed.
+/// Filters that are `None` are omitted from the query.
+///
+/// # Arguments
+///
+/// * `cik` - Company CIK or ticker
+/// * `form_type` - Form type filter (e.g., "10-K"); matches as a prefix on EDGAR
+/// * `dateb` - Only filings before this date (YYYYMMDD)
+/// * `count` - Maximum number of entries to return
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_browse_edgar_url;
+///
+/// assert_eq!(
+///     build_browse_edgar_url("320193", Some("10-K"), None, Some(40)),
+///     "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=0000320193&type=10-K&count=40&output=atom"
+/// );
+/// ```
+pub fn build_browse_edgar_url(cik: &str, form_type: Option<&str>, dateb: Option<&str>, count: Option<u32>) -> String {
+    let cik = normalize_cik(cik).unwrap_or_else(|_| cik.trim().to_string());
+
+    let mut url = format!(
+        "{}?action=getcompany&CIK={}",
+        BROWSE_EDGAR_BASE,
+        encode_query_value(&cik)
+    );
+    if let Some(form_type) = form_type {
+        url.push_str(&format!("&type={}", encode_query_value(form_type)));
+    }
+    if let Some(dateb) = dateb {
+        url.push_str(&format!("&dateb={}", encode_query_value(dateb)));
+    }
+    if let Some(count) = count {
+        url.push_str(&format!("&count={}", count));
+    }
+    url.push_str("&output=atom");
+    url
+}
+
+/// Build a browse-edgar company name search URL (Atom output).
+///
+/// EDGAR matches `name` as a prefix of company names, case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_company_search_url;
+///
+/// assert_eq!(
+///     build_company_search_url("Apple Inc"),
+///     "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&company=Apple%20Inc&output=atom"
+/// );
+/// ```
+pub fn build_company_search_url(name: &str) -> String {
+    format!(
+        "{}?action=getcompany&company={}&output=atom",
+        BROWSE_EDGAR_BASE,
+        encode_query_value(name.trim())
+    )
+}
+
+/// Build the URL of the EDGAR current events Atom feed.
+///
+/// # Arguments
+///
+/// * `form_type` - Form type filter (e.g., "8-K"); `None` lists all forms
+/// * `count` - Number of entries to request
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_current_events_url;
+///
+/// assert_eq!(
+///     build_current_events_url(Some("8-K"), 40),
+///     "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&type=8-K&owner=include&count=40&output=atom"
+/// );
+/// ```
+pub fn build_current_events_url(form_type: Option<&str>, count: u32) -> String {
+    build_current_events_page_url(form_type, 0, count)
+}
+
+/// Build the URL of one page of the EDGAR current events Atom feed.
+///
+/// `start` is the zero-based offset of the first entry; the parameter is
+/// omitted for the first page.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_current_events_page_url;
+///
+/// assert_eq!(
+///     build_current_events_page_url(Some("8-K"), 100, 100),
+///     "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&type=8-K&owner=include&start=100&count=100&output=atom"
+/// );
+/// ```
+pub fn build_current_events_page_url(form_type: Option<&str>, start: u32, count: u32) -> String {
+    let mut url = format!("{}?action=getcurrent", BROWSE_EDGAR_BASE);
+    if let Some(form_type) = form_type {
+        url.push_str(&format!("&type={}", encode_query_value(form_type)));
+    }
+    url.push_str("&owner=include");
+    if start > 0 {
+        url.push_str(&format!("&start={}", start));
+    }
+    url.push_str(&format!("&count={}&output=atom", count));
+    url
+}
+
+/// Build the URL of a quarterly insider transactions dataset archive.
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::build_insider_dataset_url;
+///
+/// assert_eq!(
+///     build_insider_dataset_url(2023, 4),
+///     "https://www.sec.gov/files/structureddata/data/insider-transactions-data-sets/2023q4_form345.zip"
+/// );
+/// ```
+pub fn build_insider_dataset_url(year: u16, quarter: u8) -> String {
+    format!("{}/{}q{}_form345.zip", INSIDER_DATASETS_BASE, year, quarter)
+}
+
+/// Class of an SEC URL, which decides how it is requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UrlKind {
+    /// JSON data APIs on `data.sec.gov` (submissions, company facts)
+    DataApi,
+    /// Filing documents under `www.sec.gov/Archives`
+    Archive,
+    /// Full-text search on `efts.sec.gov`
+    FullTextSearch,
+    /// Anything else, including other `www.sec.gov` pages and non-SEC hosts
+    Other,
+}
+
+/// Classify a URL by SEC host and path.
+///
+/// Host names are matched case-insensitively. Relative or unparseable
+/// URLs are [`UrlKind::Other`].
+///
+/// # Examples
+///
+/// ```
+/// use sec_o3::utils::{url_kind, UrlKind};
+///
+/// assert_eq!(url_kind("https://data.sec.gov/submissions/CIK0000320193.json"), UrlKind::DataApi);
+/// assert_eq!(url_kind("https://www.sec.gov/Archives/edgar/data/320193/"), UrlKind::Archive);
+/// assert_eq!(url_kind("https://www.sec.gov/cgi-bin/browse-edgar"), UrlKind::Other);
+/// ```
+pub fn url_kind(url: &str) -> UrlKind {
+    let Ok(url) = url::Url::parse(url.trim()) else {
+        return UrlKind::Other;
+    };
+
+    match url.host_str() {
+        Some("data.sec.gov") => UrlKind::DataApi,
+        Some("efts.sec.gov") => UrlKind::FullTextSearch,
+        Some("www.sec.gov" | "sec.gov") if url.path().starts_with("/Archives/") => UrlKind::Archive,
+        _ => UrlKind::Other,
+    }
+}
+
+/// Percent-encode a query parameter value (e.g., "DEF 14A" -> "DEF%2014A").
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DASHED: &str = "0000320193-23-000106";
+    const UNDASHED: &str = "000032019323000106";
+
+    #[test]
+    fn test_builders_accept_both_accession_forms() {
+        assert_eq!(
+            build_filing_dir_url("320193", DASHED),
+            build_filing_dir_url("320193", UNDASHED)
+        );
+        assert_eq!(build_filing_url("320193", DASHED), build_filing_url("320193", UNDASHED));
+        assert_eq!(
+            build_full_text_url("320193", DASHED),
+            build_full_text_url("320193", UNDASHED)
+        );
+
+        assert_eq!(
+            build_full_text_url("320193", UNDASHED),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106.txt"
+        );
+    }
+
+    #[test]
+    fn test_index_json_and_html_urls() {
+        let json = build_index_json_url("CIK0000320193", UNDASHED);
+        let html = build_filing_url("320193", DASHED);
+
+        assert_eq!(
+            json,
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/index.json"
+        );
+        assert_eq!(
+            html,
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/0000320193-23-000106-index.html"
+        );
+        assert_eq!(
+            json.strip_suffix("index.json"),
+            Some(build_filing_dir_url("320193", DASHED).as_str())
+        );
+        assert_eq!(build_filing_url("0000320193", UNDASHED), html);
+    }
+
+    #[test]
+    fn test_try_builders_validate_accession() {
+        assert_eq!(
+            try_build_full_text_url("320193", DASHED).unwrap(),
+            build_full_text_url("320193", DASHED)
+        );
+        assert_eq!(
+            try_build_filing_url("320193", UNDASHED).unwrap(),
+            build_filing_url("320193", DASHED)
+        );
+        assert_eq!(
+            try_build_filing_dir_url("320193", &format!(" {} ", DASHED)).unwrap(),
+            build_filing_dir_url("320193", DASHED)
+        );
+
+        for malformed in [
+            "",
+            "not-an-accession",
+            "0000320193-23-00010",
+            "00003201932-3-000106",
+            "0000320193_23_000106",
+        ] {
+            assert!(try_build_full_text_url("320193", malformed).is_err(), "{:?}", malformed);
+            assert!(try_build_filing_url("320193", malformed).is_err(), "{:?}", malformed);
+            assert!(
+                try_build_filing_dir_url("320193", malformed).is_err(),
+                "{:?}",
+                malformed
+            );
+        }
+    }
+
+    #[test]
+    fn test_url_kind() {
+        assert_eq!(
+            url_kind("https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json"),
+            UrlKind::DataApi
+        );
+        assert_eq!(
+            url_kind("HTTPS://DATA.SEC.GOV/submissions/CIK0000320193.json"),
+            UrlKind::DataApi
+        );
+        assert_eq!(
+            url_kind(&build_filing_url("320193", "0000320193-23-000106")),
+            UrlKind::Archive
+        );
+        assert_eq!(url_kind("https://sec.gov/Archives/edgar/full-index/"), UrlKind::Archive);
+        assert_eq!(
+            url_kind("https://efts.sec.gov/LATEST/search-index?q=%22climate%22"),
+            UrlKind::FullTextSearch
+        );
+        assert_eq!(url_kind(&build_current_events_url(None, 40)), UrlKind::Other);
+        assert_eq!(
+            url_kind("https://www.sec.gov/files/company_tickers.json"),
+            UrlKind::Other
+        );
+        assert_eq!(url_kind("https://example.com/Archives/doc.htm"), UrlKind::Other);
+        assert_eq!(url_kind("/Archives/doc.htm"), UrlKind::Other);
+    }
+
+    #[test]
+    fn test_browse_edgar_url_without_filters() {
+        assert_eq!(
+            build_browse_edgar_url("CIK320193", None, None, None),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=0000320193&output=atom"
+        );
+        assert_eq!(
+            build_browse_edgar_url(" aapl ", None, None, None),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=aapl&output=atom"
+        );
+    }
+
+    #[test]
+    fn test_browse_edgar_url_with_filters() {
+        assert_eq!(
+            build_browse_edgar_url("320193", Some("DEF 14A"), Some("20231231"), Some(100)),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=0000320193\
+             &type=DEF%2014A&dateb=20231231&count=100&output=atom"
+        );
+        assert_eq!(
+            build_browse_edgar_url("320193", Some("10-K/A"), Some("20231231"), None),
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&CIK=0000320193\
+             &type=10-K%2FA&dateb=20231231&output=atom"
+        );
+    }
+}